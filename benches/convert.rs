@@ -0,0 +1,85 @@
+//! Measures `AsciiConverter::convert` for single digit, small, medium and
+//! large numbers with each letter mode and a large custom corpus, and a batch
+//! of consecutive numbers (allocating, reusing a buffer and inline). Each
+//! size is measured with `convert_inline` too, and so are outputs that are too
+//! long to be kept inline. Single digit numbers mostly measure the fixed cost
+//! of each call.
+//!
+//! Run with `cargo bench --bench convert`.
+
+use std::{hint::black_box, time::Instant};
+
+use asciinum::*;
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    println!(
+        "{:<32} {:>10.1} ns/iter",
+        name,
+        start.elapsed().as_nanos() as f64 / iterations as f64
+    );
+}
+
+fn main() {
+    let large_corpus: String = ('\u{4e00}'..).take(1000).collect();
+    let converters = [
+        ("dai", AsciiConverter::new(&"dai".parse().expect("valid"))),
+        ("das", AsciiConverter::new(&"das".parse().expect("valid"))),
+        ("dao", AsciiConverter::new(&"dao".parse().expect("valid"))),
+        (
+            "custom (1000 characters)",
+            AsciiConverter::from_corpus(&large_corpus).expect("valid corpus"),
+        ),
+    ];
+    for (name, converter) in &converters {
+        println!("{}", name);
+        for (size, number) in [
+            ("single digit", 1),
+            ("small", 61),
+            ("medium", u64::MAX as u128),
+            ("large", u128::MAX),
+        ] {
+            bench(&format!("  {}", size), ITERATIONS, || {
+                converter.convert(black_box(number))
+            });
+            bench(&format!("  {}, inline", size), ITERATIONS, || {
+                converter.convert_inline(black_box(number))
+            });
+        }
+    }
+
+    // 128 digits don't fit inline, so `convert_inline` allocates too
+    let converter = AsciiConverter::from_corpus("01").expect("valid corpus");
+    println!("binary, longer than inline capacity");
+    bench("  large", ITERATIONS, || {
+        converter.convert(black_box(u128::MAX))
+    });
+    bench("  large, inline", ITERATIONS, || {
+        converter.convert_inline(black_box(u128::MAX))
+    });
+
+    let converter = AsciiConverter::new(&RadixSettings::default());
+    println!("dao batch");
+    bench("  0..100_000", 10, || {
+        for number in 0..100_000u128 {
+            black_box(converter.convert(number));
+        }
+    });
+    bench("  0..100_000, reused buffer", 10, || {
+        let mut out = String::new();
+        for number in 0..100_000u128 {
+            converter.convert_into(number, &mut out);
+            black_box(&out);
+        }
+    });
+    bench("  0..100_000, inline", 10, || {
+        for number in 0..100_000u128 {
+            black_box(converter.convert_inline(number));
+        }
+    });
+}
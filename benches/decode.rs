@@ -0,0 +1,49 @@
+//! Compares `AsciiConverter::decode` with a decoder that scans the corpus for
+//! each character, on the longest tokens each corpus can decode.
+//!
+//! Run with `cargo bench --bench decode`.
+
+use std::{hint::black_box, time::Instant};
+
+use asciinum::*;
+
+const ITERATIONS: u32 = 200_000;
+
+fn decode_linear(corpus: &str, ascii: &str) -> Option<u128> {
+    let base = corpus.chars().count() as u128;
+    ascii.chars().try_fold(0u128, |number, ch| {
+        let digit = corpus.chars().position(|c| c == ch)?;
+        number.checked_mul(base)?.checked_add(digit as u128)
+    })
+}
+
+fn bench(name: &str, mut f: impl FnMut() -> Option<u128>) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    println!(
+        "{:<32} {:>8.1} ns/iter",
+        name,
+        start.elapsed().as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    for (name, converter) in [
+        ("dai", AsciiConverter::new(&"dai".parse().expect("valid"))),
+        ("aas", AsciiConverter::new(&"aas".parse().expect("valid"))),
+        ("unambiguous", AsciiConverter::unambiguous()),
+        (
+            "greek",
+            AsciiConverter::from_corpus("αβγδεζηθικλμνξοπρστυφχψω").expect("valid corpus"),
+        ),
+    ] {
+        let token = converter.convert(u128::MAX);
+        let corpus = converter.corpus();
+        assert_eq!(decode_linear(corpus, &token), Some(u128::MAX));
+        println!("{} ({} characters)", name, token.chars().count());
+        bench("  table", || converter.decode(black_box(&token)).ok());
+        bench("  linear scan", || decode_linear(corpus, black_box(&token)));
+    }
+}
@@ -1,22 +1,67 @@
-use std::num::NonZeroUsize;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::{cmp::Ordering, fmt, iter::FusedIterator, num::NonZeroUsize, str::FromStr};
 
 const SYMBOLS: &str = r##"!"#$%&'()*+,-./:;<=>?@[\]^_`{|}~"##;
 const SYMBOLS_UNIXSAFE: &str = r##"!"#$%&'()*+,-.:;<=>?@[\]^_`{|}~"##;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum RadixSymbols {
     All,
     /// excludes '/'
     UnixSafe,
     Disabled,
+    /// Given characters, in the given order. These must not overlap with the
+    /// rest of the corpus, see [`AsciiConverter::try_new`].
+    Custom(String),
+}
+
+impl RadixSymbols {
+    /// Returns how many characters these symbols add to the corpus, or
+    /// `None` for [`RadixSymbols::Custom`]. It's usable in constants.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// const SYMBOLS: Option<usize> = RadixSymbols::UnixSafe.preset_len();
+    /// assert_eq!(SYMBOLS, Some(31));
+    /// ```
+    pub const fn preset_len(&self) -> Option<usize> {
+        match self {
+            RadixSymbols::All => Some(SYMBOLS.len()),
+            RadixSymbols::UnixSafe => Some(SYMBOLS_UNIXSAFE.len()),
+            RadixSymbols::Disabled => Some(0),
+            RadixSymbols::Custom(_) => None,
+        }
+    }
 }
 
 const NUMBERS: &str = "0123456789";
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum RadixNumbers {
     All,
     Disabled,
+    /// Given characters, in the given order. These must not overlap with the
+    /// rest of the corpus, see [`AsciiConverter::try_new`].
+    Custom(String),
+}
+
+impl RadixNumbers {
+    /// Returns how many characters these numbers add to the corpus, or
+    /// `None` for [`RadixNumbers::Custom`]. It's usable in constants.
+    pub const fn preset_len(&self) -> Option<usize> {
+        match self {
+            RadixNumbers::All => Some(NUMBERS.len()),
+            RadixNumbers::Disabled => Some(0),
+            RadixNumbers::Custom(_) => None,
+        }
+    }
 }
 
 const LETTERS_UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
@@ -24,388 +69,4200 @@ const LETTERS_LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
 const LETTERS_CONCAT: &str = constcat::concat!(LETTERS_UPPERCASE, LETTERS_LOWERCASE);
 const LETTERS_ORDERED: &str = "AaBbCcDdEeFfGgHhIiJjKkLlMmNnOoPpQqRrSsTtUuVvWwXxYyZz";
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum RadixLetters {
-    /// \[a-z\]
+    /// \[a-z\]; [`AsciiConverter::decode`] accepts uppercase letters too.
     Insensitive,
     /// \[A-Z\]\[a-z\]
     Sensitive,
     /// \[AaBb-Zz\]
     SensitiveOrdered,
+    /// Given characters, in the given order. These must not overlap with the
+    /// rest of the corpus, see [`AsciiConverter::try_new`].
+    Custom(String),
+}
+
+impl RadixLetters {
+    /// Returns how many characters these letters add to the corpus, or
+    /// `None` for [`RadixLetters::Custom`]. It's usable in constants.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// const LETTERS: Option<usize> = RadixLetters::Insensitive.preset_len();
+    /// assert_eq!(LETTERS, Some(26));
+    /// ```
+    pub const fn preset_len(&self) -> Option<usize> {
+        match self {
+            RadixLetters::Insensitive => Some(LETTERS_LOWERCASE.len()),
+            RadixLetters::Sensitive => Some(LETTERS_CONCAT.len()),
+            RadixLetters::SensitiveOrdered => Some(LETTERS_ORDERED.len()),
+            RadixLetters::Custom(_) => None,
+        }
+    }
 }
 
+const CORPUS_BASE58: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const CORPUS_CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+// check symbols are the corpus followed by 5 extra symbols, for values 32..37
+const CROCKFORD_CHECK_SYMBOLS: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+// characters which are decoded as another character of the corpus
+const CROCKFORD_ALIASES: [(char, char); 3] = [('O', '0'), ('I', '1'), ('L', '1')];
+const CROCKFORD_CHECK_BASE: NonZeroUsize = NonZeroUsize::new(37).unwrap();
+const CORPUS_UNAMBIGUOUS: &str = "23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz";
+
+/// Parts of a corpus, see [`RadixSettings::order`].
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RadixComponent {
+    Symbols,
+    Numbers,
+    Letters,
+}
+
+/// Symbols first, then numbers, then letters.
+const DEFAULT_ORDER: [RadixComponent; 3] = [
+    RadixComponent::Symbols,
+    RadixComponent::Numbers,
+    RadixComponent::Letters,
+];
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct RadixSettings {
     pub symbols: RadixSymbols,
     pub numbers: RadixNumbers,
     pub letters: RadixLetters,
+    /// Order in which components are concatenated to build the corpus. Since
+    /// digit values come from positions in the corpus, changing the order
+    /// changes the output: with numbers first, small values are written
+    /// with number characters. Each component should appear once.
+    ///
+    /// Default order is symbols, numbers, letters. RADIXOPT always uses the
+    /// default order, so other orders aren't visible in its
+    /// [`Display`](fmt::Display) output.
+    pub order: [RadixComponent; 3],
 }
 
 impl RadixSettings {
-    pub fn new(symbols: RadixSymbols, numbers: RadixNumbers, letters: RadixLetters) -> Self {
+    pub const fn new(symbols: RadixSymbols, numbers: RadixNumbers, letters: RadixLetters) -> Self {
         Self {
             symbols,
             numbers,
             letters,
+            order: DEFAULT_ORDER,
         }
     }
-    fn corpus(self: &RadixSettings) -> String {
-        String::new()
-            + (match self.symbols {
+    /// Returns a builder which starts from [`RadixSettings::default`], so
+    /// only components that differ from the default need to be set.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let settings = RadixSettings::builder()
+    ///     .letters(RadixLetters::Insensitive)
+    ///     .build();
+    /// assert_eq!(settings, "dai".parse().unwrap());
+    /// ```
+    pub fn builder() -> RadixSettingsBuilder {
+        RadixSettingsBuilder {
+            settings: Self::default(),
+        }
+    }
+    /// Returns characters that are used as digits with these settings,
+    /// ordered by their value.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let settings = RadixSettings::new(
+    ///     RadixSymbols::Disabled,
+    ///     RadixNumbers::All,
+    ///     RadixLetters::Insensitive,
+    /// );
+    /// assert_eq!(settings.corpus(), "0123456789abcdefghijklmnopqrstuvwxyz");
+    /// ```
+    ///
+    /// Components are concatenated in [`RadixSettings::order`]:
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let settings = RadixSettings::builder()
+    ///     .symbols(RadixSymbols::Custom("-_".into()))
+    ///     .letters(RadixLetters::Custom("xyz".into()))
+    ///     .order([
+    ///         RadixComponent::Letters,
+    ///         RadixComponent::Symbols,
+    ///         RadixComponent::Numbers,
+    ///     ])
+    ///     .build();
+    /// assert_eq!(settings.corpus(), "xyz-_0123456789");
+    /// ```
+    pub fn corpus(self: &RadixSettings) -> String {
+        let components = self.order.map(|component| self.component(component));
+        let mut corpus = String::with_capacity(components.iter().map(|s| s.len()).sum());
+        for component in components {
+            corpus.push_str(component);
+        }
+        corpus
+    }
+    /// Returns characters of a single component of the corpus.
+    fn component(&self, component: RadixComponent) -> &str {
+        match component {
+            RadixComponent::Symbols => match &self.symbols {
                 RadixSymbols::All => SYMBOLS,
                 RadixSymbols::UnixSafe => SYMBOLS_UNIXSAFE,
                 RadixSymbols::Disabled => "",
-            })
-            + (match self.numbers {
+                RadixSymbols::Custom(symbols) => symbols,
+            },
+            RadixComponent::Numbers => match &self.numbers {
                 RadixNumbers::All => NUMBERS,
                 RadixNumbers::Disabled => "",
-            })
-            + (match self.letters {
+                RadixNumbers::Custom(numbers) => numbers,
+            },
+            RadixComponent::Letters => match &self.letters {
                 RadixLetters::Insensitive => LETTERS_LOWERCASE,
                 RadixLetters::Sensitive => LETTERS_CONCAT,
                 RadixLetters::SensitiveOrdered => LETTERS_ORDERED,
-            })
+                RadixLetters::Custom(letters) => letters,
+            },
+        }
+    }
+    /// Returns length of [`RadixSettings::corpus`] without building it, or
+    /// `None` if a component is custom. Since it's usable in constants,
+    /// sizes of key spaces can be computed at compile time.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// const SETTINGS: &RadixSettings = &RadixSettings::new(
+    ///     RadixSymbols::Disabled,
+    ///     RadixNumbers::All,
+    ///     RadixLetters::SensitiveOrdered,
+    /// );
+    /// const BASE: usize = SETTINGS.preset_len().unwrap();
+    /// // count of 8 character tokens
+    /// const KEYSPACE: u128 = (BASE as u128).pow(8);
+    /// assert_eq!(KEYSPACE, 218_340_105_584_896);
+    /// ```
+    pub const fn preset_len(&self) -> Option<usize> {
+        let mut len = 0;
+        let mut i = 0;
+        while i < self.order.len() {
+            let component = match self.order[i] {
+                RadixComponent::Symbols => self.symbols.preset_len(),
+                RadixComponent::Numbers => self.numbers.preset_len(),
+                RadixComponent::Letters => self.letters.preset_len(),
+            };
+            match component {
+                Some(component) => len += component,
+                None => return None,
+            }
+            i += 1;
+        }
+        Some(len)
+    }
+    /// Refuses settings whose corpus has fewer than two characters.
+    fn validated(self) -> Result<Self, RadixParseError> {
+        let len = self
+            .preset_len()
+            .unwrap_or_else(|| self.corpus().chars().count());
+        if len < 2 {
+            return Err(RadixParseError::TooShortCorpus);
+        }
+        Ok(self)
+    }
+}
+
+impl Default for RadixSettings {
+    /// Same as RADIXOPT `dao`: numbers followed by ordered letters, no
+    /// symbols.
+    fn default() -> Self {
+        Self::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::All,
+            RadixLetters::SensitiveOrdered,
+        )
+    }
+}
+
+/// Builder for [`RadixSettings`], see [`RadixSettings::builder`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RadixSettingsBuilder {
+    settings: RadixSettings,
+}
+
+impl RadixSettingsBuilder {
+    pub fn symbols(mut self, symbols: RadixSymbols) -> Self {
+        self.settings.symbols = symbols;
+        self
+    }
+    pub fn numbers(mut self, numbers: RadixNumbers) -> Self {
+        self.settings.numbers = numbers;
+        self
+    }
+    pub fn letters(mut self, letters: RadixLetters) -> Self {
+        self.settings.letters = letters;
+        self
+    }
+    pub fn order(mut self, order: [RadixComponent; 3]) -> Self {
+        self.settings.order = order;
+        self
+    }
+    pub fn build(self) -> RadixSettings {
+        self.settings
+    }
+}
+
+impl fmt::Display for RadixSettings {
+    /// Writes canonical RADIXOPT string of these settings, which can be
+    /// parsed back with [`FromStr`]. Custom components have no RADIXOPT code,
+    /// they are written as their characters in brackets (e.g. `da[aeiou]`)
+    /// and can't be parsed back.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.symbols {
+            RadixSymbols::All => write!(f, "a"),
+            RadixSymbols::UnixSafe => write!(f, "u"),
+            RadixSymbols::Disabled => write!(f, "d"),
+            RadixSymbols::Custom(symbols) => write!(f, "[{}]", symbols),
+        }?;
+        match &self.numbers {
+            RadixNumbers::All => write!(f, "a"),
+            RadixNumbers::Disabled => write!(f, "d"),
+            RadixNumbers::Custom(numbers) => write!(f, "[{}]", numbers),
+        }?;
+        match &self.letters {
+            RadixLetters::Insensitive => write!(f, "i"),
+            RadixLetters::Sensitive => write!(f, "s"),
+            RadixLetters::SensitiveOrdered => write!(f, "o"),
+            RadixLetters::Custom(letters) => write!(f, "[{}]", letters),
+        }
+    }
+}
+
+/// Reasons why a RADIXOPT string can't be parsed as [`RadixSettings`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RadixParseError {
+    /// String isn't exactly 3 characters long.
+    WrongLength,
+    /// First character isn't a valid [`RadixSymbols`] code.
+    BadSymbols(char),
+    /// Second character isn't a valid [`RadixNumbers`] code.
+    BadNumbers(char),
+    /// Third character isn't a valid [`RadixLetters`] code.
+    BadLetters(char),
+    /// Chosen components give less than 2 distinct characters, which can't
+    /// be used as a corpus.
+    TooShortCorpus,
+}
+
+impl fmt::Display for RadixParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RadixParseError::WrongLength => write!(f, "must be 3 characters long"),
+            RadixParseError::BadSymbols(_) => write!(
+                f,
+                "first character of radix arg must be one of these: {{a,u,d}}"
+            ),
+            RadixParseError::BadNumbers(_) => write!(
+                f,
+                "second character of radix arg must be one of these: {{a,d}}"
+            ),
+            RadixParseError::BadLetters(_) => write!(
+                f,
+                "third character of radix arg must be one of these: {{i,s,o}}"
+            ),
+            RadixParseError::TooShortCorpus => {
+                write!(f, "radix arg must give at least 2 distinct characters")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RadixParseError {}
+
+impl FromStr for RadixSettings {
+    type Err = RadixParseError;
+
+    /// Parses RADIXOPT strings like `dao`. First character determines
+    /// symbols, second one numbers and the third one letters.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(s), Some(n), Some(l), None) =
+            (chars.next(), chars.next(), chars.next(), chars.next())
+        {
+            let symbols = match s {
+                'a' => RadixSymbols::All,
+                'u' => RadixSymbols::UnixSafe,
+                'd' => RadixSymbols::Disabled,
+                _ => return Err(RadixParseError::BadSymbols(s)),
+            };
+            let numbers = match n {
+                'a' => RadixNumbers::All,
+                'd' => RadixNumbers::Disabled,
+                _ => return Err(RadixParseError::BadNumbers(n)),
+            };
+            let letters = match l {
+                'i' => RadixLetters::Insensitive,
+                's' => RadixLetters::Sensitive,
+                'o' => RadixLetters::SensitiveOrdered,
+                _ => return Err(RadixParseError::BadLetters(l)),
+            };
+            RadixSettings::new(symbols, numbers, letters).validated()
+        } else {
+            Err(RadixParseError::WrongLength)
+        }
     }
 }
 
-/// Base translation operation as an iteration.
+/// Unsigned integer types that can be converted to another base, see
+/// [`BaseConvertIter`] and [`AsciiConverter::convert`]. It's implemented for
+/// all primitive unsigned integers.
+pub trait Convertible: Copy {
+    /// Returns how many digits `self` has in `base`. `0` has one digit.
+    fn digit_count(self, base: NonZeroUsize) -> u32;
+    /// Returns `self / base` and `self % base`.
+    fn div_rem(self, base: NonZeroUsize) -> (Self, usize);
+    /// Returns `self / base.pow(exp)` and `self % base.pow(exp)`. Callers
+    /// make sure that `self` has at most `exp + 1` digits and that
+    /// `base.pow(exp)` fits into `Self`, so quotient is always a single digit.
+    fn div_rem_pow(self, base: NonZeroUsize, exp: u32) -> (usize, Self);
+}
+
+macro_rules! impl_convertible {
+    ($($t:ty),*) => {$(
+        impl Convertible for $t {
+            fn digit_count(self, base: NonZeroUsize) -> u32 {
+                if base.get() as u128 > <$t>::MAX as u128 {
+                    // every value is smaller than base
+                    return 1;
+                }
+                // `checked_ilog` is None for 0, which still has one digit
+                self.checked_ilog(base.get() as $t).map_or(1, |log| log + 1)
+            }
+            fn div_rem(self, base: NonZeroUsize) -> (Self, usize) {
+                if base.get() as u128 > <$t>::MAX as u128 {
+                    return (0, self as usize);
+                }
+                let base = base.get() as $t;
+                // because of modulo, we know remainder is smaller than
+                // base(usize)
+                (self / base, (self % base) as usize)
+            }
+            fn div_rem_pow(self, base: NonZeroUsize, exp: u32) -> (usize, Self) {
+                if base.get() as u128 > <$t>::MAX as u128 {
+                    // only a single digit, so `exp` is 0
+                    return (self as usize, 0);
+                }
+                let divisor = (base.get() as $t).pow(exp);
+                ((self / divisor) as usize, self % divisor)
+            }
+        }
+    )*};
+}
+
+impl_convertible!(u8, u16, u32, u64, u128, usize);
+
+/// Base translation operation as an iteration. Yields digit values of a
+/// number in the given base, **least significant first**; use `.rev()` for
+/// most significant first. It always yields at least one digit, so `0` is
+/// yielded as a single `0` digit.
+///
+/// This is the building block of [`AsciiConverter`], and it's useful on its
+/// own for any kind of base conversion. Any [`Convertible`] integer can be
+/// converted.
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use asciinum::*;
+///
+/// let base = NonZeroUsize::new(10).unwrap();
+/// let digits: Vec<usize> = BaseConvertIter::new(1024u128, base).collect();
+/// assert_eq!(digits, [4, 2, 0, 1]);
+///
+/// let digits: Vec<usize> = BaseConvertIter::new(1024u16, base).rev().collect();
+/// assert_eq!(digits, [1, 0, 2, 4]);
+///
+/// let base = NonZeroUsize::new(16).unwrap();
+/// assert_eq!(BaseConvertIter::new(0xcafe_u32, base).len(), 4);
+/// ```
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-struct BaseConvertIter {
-    // starts with Some, at final step this becomes None
-    number: Option<u128>,
-    // TODO 1 should be forbidden too
+pub struct BaseConvertIter<T: Convertible = u128> {
+    // digits that are taken from the back are removed from this number
+    number: T,
+    // count of remaining digits, at final step this becomes 0
+    len: u32,
+    // 1 is forbidden too since every digit would be 0, constructors make sure
+    // that base is at least 2
     base: NonZeroUsize,
 }
 
-impl BaseConvertIter {
-    fn new(number: u128, base: NonZeroUsize) -> Self {
-        Self {
-            number: Some(number),
-            base,
+impl<T: Convertible> BaseConvertIter<T> {
+    /// Starts converting `number` to `base`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is 1, see [`BaseConvertIter::try_new`] for a
+    /// non-panicking version.
+    pub fn new(number: T, base: NonZeroUsize) -> Self {
+        Self::try_new(number, base).expect("base must be at least 2")
+    }
+    /// Starts converting `number` to `base`, or returns `None` for base 1.
+    /// Every number would be an endless run of `0` digits in base 1, so it
+    /// can't be converted.
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use asciinum::*;
+    ///
+    /// assert!(BaseConvertIter::try_new(5u8, NonZeroUsize::new(2).unwrap()).is_some());
+    /// assert!(BaseConvertIter::try_new(5u8, NonZeroUsize::MIN).is_none());
+    /// ```
+    pub fn try_new(number: T, base: NonZeroUsize) -> Option<Self> {
+        if base.get() < 2 {
+            return None;
         }
+        Some(Self {
+            number,
+            len: number.digit_count(base),
+            base,
+        })
     }
 }
 
-impl Iterator for BaseConvertIter {
+impl<T: Convertible> Iterator for BaseConvertIter<T> {
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(number) = self.number {
-            let base = self.base.get() as u128;
-            if number < base {
-                self.number = None;
-                // we know `number` is smaller than base(usize)
-                Some(number as usize)
-            } else {
-                let left = number % base;
-                self.number = Some((number - left) / base);
-                // because of modulo, we know `left` is smaller than base(usize)
-                Some(left as usize)
+        if self.len == 0 {
+            return None;
+        }
+        let (number, digit) = self.number.div_rem(self.base);
+        self.number = number;
+        self.len -= 1;
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len as usize, Some(self.len as usize))
+    }
+}
+
+impl<T: Convertible> ExactSizeIterator for BaseConvertIter<T> {}
+
+impl<T: Convertible> FusedIterator for BaseConvertIter<T> {}
+
+impl<T: Convertible> DoubleEndedIterator for BaseConvertIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // power can't overflow, since it's never bigger than the initial
+        // number
+        let (digit, number) = self.number.div_rem_pow(self.base, self.len);
+        self.number = number;
+        Some(digit)
+    }
+}
+
+/// Errors of the fallible [`AsciiConverter`] functions:
+///
+/// * [`AsciiConverter::from_corpus`], [`AsciiConverter::try_new`] and
+///   [`AsciiConverter::shuffled`] return [`Error::TooShortCorpus`] and
+///   [`Error::DuplicateChar`].
+/// * [`AsciiConverter::decode`] returns [`Error::Empty`],
+///   [`Error::InvalidChar`], [`Error::Overflow`] and [`Error::CheckMismatch`].
+/// * [`AsciiConverter::convert_fixed`] returns [`Error::Overflow`], and
+///   [`AsciiConverter::max_value_for_width`] returns [`Error::WidthTooShort`].
+/// * [`AsciiConverter::convert_grouped`] returns [`Error::SeparatorInCorpus`],
+///   and [`AsciiConverter::decode_grouped`] returns it in addition to the
+///   errors of `decode`.
+/// * [`AsciiConverter::convert_bytes_out`] returns [`Error::NonAsciiCorpus`].
+/// * [`AsciiConverter::convert_signed`] returns [`Error::SignInCorpus`], and
+///   [`AsciiConverter::decode_signed`] returns it in addition to the errors
+///   of `decode`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Error {
+    /// Corpus has less than 2 characters.
+    TooShortCorpus,
+    /// Character appears more than once in the corpus, which makes decoding
+    /// ambiguous.
+    DuplicateChar(char),
+    /// Input string to decode was empty.
+    Empty,
+    /// Character `ch` at byte offset `pos` isn't a member of the corpus.
+    InvalidChar { ch: char, pos: usize },
+    /// Decoded value doesn't fit into `u128` (or `i128` for signed values),
+    /// or converted value doesn't fit into the requested width.
+    Overflow,
+    /// Check symbol at the end doesn't match the decoded value.
+    CheckMismatch,
+    /// Group separator is a character that the converter reads as a digit.
+    SeparatorInCorpus(char),
+    /// Sign character is a character that the converter reads as a digit.
+    SignInCorpus(char),
+    /// Corpus has a character outside of ASCII, so output isn't one byte per
+    /// character.
+    NonAsciiCorpus,
+    /// Width is too short to hold any number, because of the check symbol or
+    /// the minimum width of the converter.
+    WidthTooShort,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::TooShortCorpus => write!(f, "corpus must have at least 2 characters"),
+            Error::DuplicateChar(ch) => {
+                write!(f, "character `{}` appears more than once in corpus", ch)
             }
-        } else {
-            None
+            Error::Empty => write!(f, "cannot decode empty string"),
+            Error::InvalidChar { ch, pos } => {
+                write!(f, "invalid character `{}` at position {}", ch, pos)
+            }
+            Error::Overflow => write!(f, "number too large to fit in target type"),
+            Error::CheckMismatch => write!(f, "check symbol doesn't match"),
+            Error::SeparatorInCorpus(ch) => {
+                write!(f, "separator `{}` can't be a member of corpus", ch)
+            }
+            Error::SignInCorpus(ch) => {
+                write!(f, "sign `{}` can't be a member of corpus", ch)
+            }
+            Error::NonAsciiCorpus => write!(f, "corpus must only have ASCII characters"),
+            Error::WidthTooShort => write!(f, "width is too short to hold any number"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Extra symbol that is appended to the output to detect typing errors.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum CheckSymbol {
+    /// Crockford's base32 check symbol: value of the number modulo 37.
+    Crockford,
+    /// Luhn mod N algorithm over the digits, N being the corpus length. It's
+    /// a character of the corpus.
+    Luhn,
+}
+
+impl CheckSymbol {
+    fn symbol<T: Convertible>(self, number: T, converter: &AsciiConverter) -> char {
+        match self {
+            CheckSymbol::Crockford => CROCKFORD_CHECK_SYMBOLS
+                .chars()
+                .nth(number.div_rem(CROCKFORD_CHECK_BASE).1)
+                .expect("there are 37 check symbols"),
+            CheckSymbol::Luhn => converter.chars[Self::luhn(number, converter.base)],
+        }
+    }
+    fn matches(self, number: u128, symbol: char, converter: &AsciiConverter) -> Option<bool> {
+        match self {
+            CheckSymbol::Luhn => converter
+                .digits
+                .get(symbol)
+                .map(|digit| digit == Self::luhn(number, converter.base)),
+            CheckSymbol::Crockford => {
+                let symbol = symbol.to_ascii_uppercase();
+                let symbol = CROCKFORD_ALIASES
+                    .iter()
+                    .find(|&&(alias, _)| alias == symbol)
+                    .map_or(symbol, |&(_, ch)| ch);
+                CROCKFORD_CHECK_SYMBOLS
+                    .chars()
+                    .position(|ch| ch == symbol)
+                    .map(|value| value as u128 == number % 37)
+            }
+        }
+    }
+    /// Returns the digit which makes the weighted sum of all digits a
+    /// multiple of `base`. Digits are weighted by 2 and 1 alternately,
+    /// starting with 2 from the least significant digit.
+    fn luhn<T: Convertible>(number: T, base: NonZeroUsize) -> usize {
+        let sum: usize = BaseConvertIter::new(number, base)
+            .zip([2, 1].into_iter().cycle())
+            .map(|(digit, weight)| {
+                let addend = digit * weight;
+                // doubling maps digits to distinct values modulo an odd base,
+                // but not modulo an even one; Luhn's trick of summing the two
+                // digits of doubled values makes them distinct again
+                if base.get().is_multiple_of(2) {
+                    addend / base + addend % base
+                } else {
+                    addend
+                }
+            })
+            .fold(0, |sum, addend| (sum + addend) % base);
+        (base.get() - sum) % base
+    }
+}
+
+/// Maps characters to their digit values in constant time, so that decoding
+/// doesn't need to scan the corpus for each character.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+struct DigitTable {
+    // indexed by ASCII code
+    ascii: Box<[Option<usize>; 128]>,
+    other: BTreeMap<char, usize>,
+}
+
+impl DigitTable {
+    fn new(chars: &[char]) -> Self {
+        let mut table = Self {
+            ascii: Box::new([None; 128]),
+            other: BTreeMap::new(),
+        };
+        for (digit, &ch) in chars.iter().enumerate() {
+            table.insert(ch, digit);
+        }
+        table
+    }
+    fn get(&self, ch: char) -> Option<usize> {
+        match self.ascii.get(ch as usize) {
+            Some(&digit) => digit,
+            None => self.other.get(&ch).copied(),
+        }
+    }
+    fn insert(&mut self, ch: char, digit: usize) {
+        match self.ascii.get_mut(ch as usize) {
+            Some(entry) => *entry = Some(digit),
+            None => {
+                self.other.insert(ch, digit);
+            }
+        }
+    }
+    /// Makes `alias` decode as `ch`, unless `alias` already has a value.
+    fn add_alias(&mut self, alias: char, ch: char) {
+        if let (None, Some(digit)) = (self.get(alias), self.get(ch)) {
+            self.insert(alias, digit);
+        }
+    }
+    /// Makes ASCII letters without a value decode as their other case.
+    fn ignore_case(&mut self) {
+        for ch in ('A'..='Z').chain('a'..='z') {
+            let other = if ch.is_ascii_uppercase() {
+                ch.to_ascii_lowercase()
+            } else {
+                ch.to_ascii_uppercase()
+            };
+            self.add_alias(ch, other);
         }
     }
 }
 
+/// Order of digits in the output of [`AsciiConverter::convert`], see
+/// [`AsciiConverter::with_digit_order`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum DigitOrder {
+    /// Most significant digit first, like decimal numbers are written.
+    #[default]
+    MostSignificantFirst,
+    /// Least significant digit first.
+    LeastSignificantFirst,
+}
+
+/// Converts numbers to ASCII numbers and back.
+///
+/// Converters are equal (and hash equally) when they have the same corpus,
+/// options and accepted input characters, so they can be used as keys to
+/// memoize or dedupe converters. Converters built from a RADIXOPT with
+/// [`RadixLetters::Insensitive`] letters also accept uppercase letters, so
+/// they differ from [`AsciiConverter::from_corpus`] with the same corpus.
+///
+/// ```
+/// use asciinum::*;
+///
+/// let settings = RadixSettings::default();
+/// assert_eq!(
+///     AsciiConverter::new(&settings),
+///     AsciiConverter::from_corpus(&settings.corpus()).unwrap()
+/// );
+/// ```
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct AsciiConverter {
     corpus: String,
+    // same as `corpus`, but indexable by digit value
+    chars: Box<[char]>,
+    // length of `chars`, kept so that conversions don't check it every time
+    base: NonZeroUsize,
+    // inverse of `chars`, including characters that `decode` accepts in place
+    // of a corpus character
+    digits: DigitTable,
+    check: Option<CheckSymbol>,
+    order: DigitOrder,
+    // output is padded with zero digits to at least this many characters
+    min_width: usize,
+    // marks negative numbers of `convert_signed`
+    sign: char,
+    // zero is written without any digits
+    zero_as_empty: bool,
 }
 
 impl AsciiConverter {
+    /// Builds a converter for the corpus of `settings`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if custom components or a repeated component in
+    /// [`RadixSettings::order`] make the corpus invalid, see
+    /// [`AsciiConverter::try_new`] for a non-panicking version. Settings
+    /// without those never panic.
     pub fn new(settings: &RadixSettings) -> Self {
-        Self {
-            corpus: settings.corpus(),
+        Self::try_new(settings).expect("invalid corpus for RadixSettings")
+    }
+    /// Builds a converter for the corpus of `settings`, or returns an error if
+    /// that corpus isn't usable (see [`AsciiConverter::from_corpus`]). This
+    /// can only happen with custom components or a repeated component in
+    /// [`RadixSettings::order`].
+    ///
+    /// Since [`RadixLetters::Insensitive`] decodes uppercase letters as
+    /// lowercase ones, an uppercase letter in custom symbols is a duplicate of
+    /// its lowercase letter with these settings.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let settings = RadixSettings::builder()
+    ///     .letters(RadixLetters::Custom("aeiou".into()))
+    ///     .build();
+    /// assert_eq!(AsciiConverter::try_new(&settings).unwrap().convert(15u128), "10");
+    ///
+    /// let settings = RadixSettings::builder()
+    ///     .letters(RadixLetters::Custom("x1".into()))
+    ///     .build();
+    /// assert_eq!(
+    ///     AsciiConverter::try_new(&settings),
+    ///     Err(Error::DuplicateChar('1'))
+    /// );
+    /// ```
+    pub fn try_new(settings: &RadixSettings) -> Result<Self, Error> {
+        let mut converter = Self::from_corpus(&settings.corpus())?;
+        if settings.letters == RadixLetters::Insensitive {
+            let folded = converter.chars.iter().find(|&&ch| {
+                let lower = ch.to_ascii_lowercase();
+                lower != ch && converter.digits.get(lower).is_some()
+            });
+            if let Some(&ch) = folded {
+                return Err(Error::DuplicateChar(ch));
+            }
+            converter.digits.ignore_case();
+        }
+        Ok(converter)
+    }
+    /// Builds a converter which uses characters of `corpus` as digits, in
+    /// the given order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TooShortCorpus`] for corpora with less than 2
+    /// characters, and [`Error::DuplicateChar`] with the first repeated
+    /// character.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::from_corpus("0123456789ABCDEFGHJKMNPQRSTVWXYZ").unwrap();
+    /// assert_eq!(converter.convert(1234u128), "16J");
+    /// assert_eq!(
+    ///     AsciiConverter::from_corpus("abca"),
+    ///     Err(Error::DuplicateChar('a'))
+    /// );
+    /// ```
+    pub fn from_corpus(corpus: &str) -> Result<Self, Error> {
+        let chars: Box<[char]> = corpus.chars().collect();
+        if chars.len() < 2 {
+            return Err(Error::TooShortCorpus);
+        }
+        let mut seen = BTreeSet::new();
+        if let Some(&ch) = chars.iter().find(|&&ch| !seen.insert(ch)) {
+            return Err(Error::DuplicateChar(ch));
+        }
+        Ok(Self {
+            corpus: corpus.to_owned(),
+            digits: DigitTable::new(&chars),
+            base: NonZeroUsize::new(chars.len()).expect("we know that chars.len() is > 0"),
+            chars,
+            check: None,
+            order: DigitOrder::MostSignificantFirst,
+            min_width: 0,
+            sign: '-',
+            zero_as_empty: false,
+        })
+    }
+    /// Builds a converter which uses characters of `base` as digits, but in
+    /// an order shuffled with `seed`. Outputs are harder to guess than with
+    /// `base` itself, while they still decode with a converter shuffled with
+    /// the same seed. Same `base` and `seed` always give the same order,
+    /// across runs, platforms and versions of this crate.
+    ///
+    /// Shuffling isn't encryption: with a few known numbers and their
+    /// outputs, the order can be found easily.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`AsciiConverter::from_corpus`].
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::shuffled("0123456789ABCDEFGHJKMNPQRSTVWXYZ", 42).unwrap();
+    /// assert_eq!(converter.corpus(), "2P73AH9M1TE0GBV5CR4XZSYD8QF6KJWN");
+    /// assert_eq!(converter.decode(&converter.convert(1234u128)), Ok(1234));
+    /// ```
+    pub fn shuffled(base: &str, seed: u64) -> Result<Self, Error> {
+        let mut chars: Vec<char> = base.chars().collect();
+        let mut rng = SplitMix64(seed);
+        // Fisher-Yates
+        for i in (1..chars.len()).rev() {
+            let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+            chars.swap(i, j);
         }
+        Self::from_corpus(&chars.into_iter().collect::<String>())
     }
-    /// Does decimal to ascii numbers conversion.
+    /// Does decimal to ascii numbers conversion. `decimal` can be any
+    /// [`Convertible`] integer, same value gives same output regardless of
+    /// its type.
     ///
     /// ```
+    /// use asciinum::*;
+    ///
     /// let converter = AsciiConverter::new(&RadixSettings::new(
     ///     RadixSymbols::Disabled,
     ///     RadixNumbers::Disabled,
     ///     RadixLetters::Insensitive,
     /// ));
-    /// assert_eq!(converter.convert(123), "et");
+    /// assert_eq!(converter.convert(123u128), "et");
+    ///
+    /// let id: u64 = 123;
+    /// assert_eq!(converter.convert(id), "et");
     /// ```
-    pub fn convert(&self, decimal: u128) -> String {
-        let number: String = BaseConvertIter::new(
-            decimal,
-            NonZeroUsize::new(self.corpus.len()).expect("we know that corpus.len() is > 0"),
-        )
-        .map(|digit| {
-            self.corpus
-                .chars()
-                .nth(digit)
-                .expect("corpus.len() will be always bigger than digit itself")
-        })
-        .collect();
-        // it's okay to use .rev() here becase we know that every character in this
-        // string is an ASCII character
-        number.chars().rev().collect()
+    pub fn convert<T: Convertible>(&self, decimal: T) -> String {
+        let mut number = String::new();
+        self.convert_into(decimal, &mut number);
+        number
     }
-}
-
-pub trait TrimAsciiControlCharacters {
-    /// Returns a byte slice with leading and trailing ASCII control bytes
-    /// removed.
+    /// Returns characters that are used as digits, ordered by their value.
+    /// Corpus alone isn't a key for converters, since options like
+    /// [`AsciiConverter::with_checksum`] and case insensitive decoding of
+    /// [`RadixLetters::Insensitive`] aren't part of it; use the converter
+    /// itself instead.
     ///
-    /// 'Control bytes' refers to the definition used by
-    /// [`u8::is_ascii_control`].
+    /// ```
+    /// use asciinum::*;
     ///
-    /// # Examples
+    /// assert_eq!(
+    ///     AsciiConverter::crockford_base32().corpus(),
+    ///     "0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+    /// );
+    /// ```
+    pub fn corpus(&self) -> &str {
+        &self.corpus
+    }
+    /// Builds a converter that is suitable for codes which are read and
+    /// typed by humans. Its corpus is numbers and letters, except the ones
+    /// that are easy to confuse with each other: `0`, `O`, `o`, `1`, `I` and
+    /// `l`. That leaves these 56 characters:
+    ///
+    /// `23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz`
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// assert_eq!(AsciiConverter::unambiguous().convert(123456u128), "hNa");
+    /// ```
+    pub fn unambiguous() -> Self {
+        Self::from_corpus(CORPUS_UNAMBIGUOUS).expect("unambiguous corpus is valid")
+    }
+    /// Builds a converter with the Base58 alphabet used by Bitcoin:
+    ///
+    /// `123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz`
+    ///
+    /// This converter works on numbers, so byte strings need to be read as a
+    /// big-endian number first. Base58 represents each leading zero byte of
+    /// a byte string with a `1`, which a number can't carry; callers that
+    /// need this should prepend one `1` per leading zero byte themselves.
     ///
     /// ```
+    /// use asciinum::*;
+    ///
+    /// let bytes = *b"\0\0\0\0Hello World!";
     /// assert_eq!(
-    ///     b"\r hello world\n ".trim_ascii_control(),
-    ///     b" hello world\n "
+    ///     AsciiConverter::base58().convert(u128::from_be_bytes(bytes)),
+    ///     "2NEpo7TZRRrLZSi2U"
     /// );
-    /// assert_eq!(b"  ".trim_ascii_control(), b"  ");
-    /// assert_eq!(b"".trim_ascii_control(), b"");
     /// ```
-    fn trim_ascii_control(&self) -> &[u8];
-}
-
-impl TrimAsciiControlCharacters for [u8] {
-    fn trim_ascii_control(&self) -> &[u8] {
-        let from = match self.iter().position(|x| !x.is_ascii_control()) {
-            Some(i) => i,
-            None => return &self[0..0],
-        };
-        let to = self
-            .iter()
-            .rposition(|x| !x.is_ascii_control())
-            .expect("we know that at least one non-control ascii character exists");
-        &self[from..=to]
+    pub fn base58() -> Self {
+        Self::from_corpus(CORPUS_BASE58).expect("base58 corpus is valid")
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_base_convert_iter() {
-        assert_eq!(
-            BaseConvertIter::new(0, NonZeroUsize::new(2).expect("not zero"))
-                .collect::<Vec<usize>>(),
-            vec![0]
+    /// Builds a converter with Crockford's Base32 alphabet:
+    ///
+    /// `0123456789ABCDEFGHJKMNPQRSTVWXYZ`
+    ///
+    /// As the specification suggests, [`AsciiConverter::decode`] accepts
+    /// lowercase characters as well, and reads `O` as `0`, `I` and `L` as
+    /// `1`.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.convert(1234u128), "16J");
+    /// assert_eq!(converter.decode("i6j"), Ok(1234));
+    /// ```
+    pub fn crockford_base32() -> Self {
+        let mut converter = Self::from_corpus(CORPUS_CROCKFORD).expect("crockford corpus is valid");
+        for (alias, ch) in CROCKFORD_ALIASES {
+            converter.digits.add_alias(alias, ch);
+        }
+        converter.digits.ignore_case();
+        converter
+    }
+    /// Same as [`AsciiConverter::crockford_base32`], but output ends with
+    /// Crockford's check symbol (value modulo 37, expressed with the corpus
+    /// and `*~$=U`). [`AsciiConverter::decode`] expects and verifies this
+    /// symbol.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32_with_check();
+    /// assert_eq!(converter.convert(1234u128), "16JD");
+    /// assert_eq!(converter.decode("16JD"), Ok(1234));
+    /// assert_eq!(converter.decode("16KD"), Err(Error::CheckMismatch));
+    /// ```
+    pub fn crockford_base32_with_check() -> Self {
+        Self {
+            check: Some(CheckSymbol::Crockford),
+            ..Self::crockford_base32()
+        }
+    }
+    /// Returns the same converter, but its output ends with a check
+    /// character to detect typing errors. [`AsciiConverter::decode`] expects
+    /// and verifies this character.
+    ///
+    /// Check character is a member of the corpus, calculated with the Luhn mod
+    /// N algorithm (N being the corpus length). It detects every single
+    /// character substitution and every transposition of adjacent characters,
+    /// except for swapping the zero digit with the last digit of an even
+    /// length corpus. This replaces the check symbol of
+    /// [`AsciiConverter::crockford_base32_with_check`].
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::default()).with_checksum();
+    /// assert_eq!(converter.convert(123456u128), "L7CH");
+    /// assert_eq!(converter.decode("L7CH"), Ok(123456));
+    /// assert_eq!(converter.decode("L8CH"), Err(Error::CheckMismatch));
+    /// assert_eq!(converter.decode("7LCH"), Err(Error::CheckMismatch));
+    /// ```
+    pub fn with_checksum(self) -> Self {
+        Self {
+            check: Some(CheckSymbol::Luhn),
+            ..self
+        }
+    }
+    /// Returns the same converter, but its digits are written (and decoded)
+    /// in `order`. Check symbol stays at the end in both orders, and padding
+    /// of [`AsciiConverter::convert_padded`] always goes to the most
+    /// significant side.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::default())
+    ///     .with_digit_order(DigitOrder::LeastSignificantFirst);
+    /// assert_eq!(converter.convert(123456u128), "C7L");
+    /// assert_eq!(converter.decode("C7L"), Ok(123456));
+    /// ```
+    pub fn with_digit_order(self, order: DigitOrder) -> Self {
+        Self { order, ..self }
+    }
+    /// Returns the same converter, but its output is always padded to at
+    /// least `min_width` characters, like [`AsciiConverter::convert_padded`]
+    /// does. Every function that writes output uses this width, and
+    /// [`AsciiConverter::encoded_len`] takes it into account. `0` means no
+    /// padding, which is the default.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32().with_min_width(6);
+    /// assert_eq!(converter.convert(1234u128), "00016J");
+    /// assert_eq!(converter.decode("00016J"), Ok(1234));
+    /// ```
+    pub fn with_min_width(self, min_width: usize) -> Self {
+        Self { min_width, ..self }
+    }
+    /// Returns the same converter, but `0` is written as an empty string
+    /// instead of a single zero digit, and an empty string decodes to `0`.
+    /// Padding and the check symbol are still added, so with
+    /// [`AsciiConverter::with_min_width`] zero is written with zero digits
+    /// only.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::default()).with_zero_as_empty();
+    /// assert_eq!(converter.convert(0u128), "");
+    /// assert_eq!(converter.decode(""), Ok(0));
+    /// assert_eq!(converter.convert(61u128), "z");
+    /// ```
+    pub fn with_zero_as_empty(self) -> Self {
+        Self {
+            zero_as_empty: true,
+            ..self
+        }
+    }
+    /// Returns whether `decimal` is written without any digits, see
+    /// [`AsciiConverter::with_zero_as_empty`].
+    fn is_empty_zero<T: Convertible>(&self, decimal: T) -> bool {
+        // a single digit that is 0 is only possible for zero
+        self.zero_as_empty
+            && decimal.digit_count(self.base) == 1
+            && decimal.div_rem(self.base).1 == 0
+    }
+    /// Returns count of characters in the corpus, which is the base of
+    /// numbers. Check symbols aren't counted.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// assert_eq!(AsciiConverter::new(&RadixSettings::default()).base(), 62);
+    /// assert_eq!(AsciiConverter::crockford_base32_with_check().base(), 32);
+    /// ```
+    pub fn base(&self) -> usize {
+        self.base.get()
+    }
+    /// Returns the character that [`AsciiConverter::convert`] writes for
+    /// `digit`, or `None` if `digit` isn't less than
+    /// [`AsciiConverter::base`].
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.char_for_digit(18), Some('J'));
+    /// assert_eq!(converter.char_for_digit(32), None);
+    /// ```
+    pub fn char_for_digit(&self, digit: usize) -> Option<char> {
+        self.chars.get(digit).copied()
+    }
+    /// Returns the digit value that [`AsciiConverter::decode`] reads for
+    /// `ch`, or `None` if `ch` isn't a digit. Besides corpus characters, this
+    /// includes characters accepted in place of them, like uppercase letters
+    /// of [`RadixLetters::Insensitive`]. Check symbols aren't digits.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.digit_for_char('J'), Some(18));
+    /// assert_eq!(converter.digit_for_char('j'), Some(18));
+    /// assert_eq!(converter.digit_for_char('O'), Some(0));
+    /// assert_eq!(converter.digit_for_char('U'), None);
+    /// ```
+    pub fn digit_for_char(&self, ch: char) -> Option<usize> {
+        self.digits.get(ch)
+    }
+    /// Same as [`AsciiConverter::convert`], but writes output into `out`
+    /// instead of allocating a new string. `out` is cleared first, so that
+    /// same buffer can be reused across calls.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::new(
+    ///     RadixSymbols::Disabled,
+    ///     RadixNumbers::Disabled,
+    ///     RadixLetters::Insensitive,
+    /// ));
+    /// let mut out = String::new();
+    /// converter.convert_into(123u128, &mut out);
+    /// assert_eq!(out, "et");
+    /// ```
+    pub fn convert_into<T: Convertible>(&self, decimal: T, out: &mut String) {
+        out.clear();
+        self.push_converted(decimal, out);
+    }
+    /// Appends output of [`AsciiConverter::convert`] to `out`.
+    fn push_converted<T: Convertible>(&self, decimal: T, out: &mut impl Extend<char>) {
+        self.push_padded(decimal, self.min_width, self.chars[0], out);
+    }
+    /// Appends output of `decimal` to `out`, padded with `pad` to at least
+    /// `min_width` characters.
+    fn push_padded<T: Convertible>(
+        &self,
+        decimal: T,
+        min_width: usize,
+        pad: char,
+        out: &mut impl Extend<char>,
+    ) {
+        let padding =
+            core::iter::repeat_n(pad, min_width.saturating_sub(self.unpadded_len(decimal)));
+        match self.order {
+            DigitOrder::MostSignificantFirst => {
+                out.extend(padding);
+                self.push_digits(decimal, out);
+            }
+            DigitOrder::LeastSignificantFirst => {
+                self.push_digits(decimal, out);
+                out.extend(padding);
+            }
+        }
+        if let Some(check) = self.check {
+            out.extend([check.symbol(decimal, self)]);
+        }
+    }
+    /// Appends digits of `decimal` to `out`, without the check symbol.
+    fn push_digits<T: Convertible>(&self, decimal: T, out: &mut impl Extend<char>) {
+        if self.is_empty_zero(decimal) {
+            return;
+        }
+        let digits = BaseConvertIter::new(decimal, self.base);
+        match self.order {
+            DigitOrder::MostSignificantFirst => {
+                out.extend(digits.rev().map(|digit| self.chars[digit]))
+            }
+            DigitOrder::LeastSignificantFirst => out.extend(digits.map(|digit| self.chars[digit])),
+        }
+    }
+    /// Same as [`AsciiConverter::convert`], but output is kept on the stack
+    /// if it fits into [`InlineToken::CAPACITY`] bytes, counting the digits,
+    /// the check symbol and the padding of [`AsciiConverter::with_min_width`].
+    /// Longer outputs are allocated.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::default());
+    /// let token = converter.convert_inline(123456u128);
+    /// assert_eq!(token, "L7C");
+    /// assert!(token.is_inline());
+    /// assert_eq!(token.to_uppercase(), "L7C");
+    /// ```
+    pub fn convert_inline<T: Convertible>(&self, decimal: T) -> InlineToken {
+        let mut buf = InlineBuf::default();
+        self.push_converted(decimal, &mut buf);
+        match buf.spilled {
+            Some(string) => InlineToken(InlineRepr::Heap(string)),
+            None => InlineToken(InlineRepr::Inline {
+                buf: buf.buf,
+                len: buf.len,
+            }),
+        }
+    }
+    /// Same as [`AsciiConverter::convert`], but returns ASCII bytes of the
+    /// output instead of a string, for byte oriented sinks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NonAsciiCorpus`] if the corpus has characters outside
+    /// of ASCII. Every built-in corpus is ASCII.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.convert_bytes_out(1234u128), Ok(b"16J".to_vec()));
+    ///
+    /// let converter = AsciiConverter::from_corpus("αβγ").unwrap();
+    /// assert_eq!(converter.convert_bytes_out(1234u128), Err(Error::NonAsciiCorpus));
+    /// ```
+    pub fn convert_bytes_out<T: Convertible>(&self, decimal: T) -> Result<Vec<u8>, Error> {
+        if !self.corpus.is_ascii() {
+            return Err(Error::NonAsciiCorpus);
+        }
+        let mut bytes = AsciiBytes(Vec::with_capacity(self.encoded_len(decimal)));
+        self.push_converted(decimal, &mut bytes);
+        Ok(bytes.0)
+    }
+    /// Returns a value which writes output of [`AsciiConverter::convert`]
+    /// when it's formatted, without allocating a string for it. Formatting
+    /// options like width and alignment are ignored.
+    ///
+    /// ```
+    /// use asciinum::*;
+    /// use std::fmt::Write;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::default());
+    /// let mut out = String::new();
+    /// write!(out, "id: {}", converter.display(123456u128)).unwrap();
+    /// assert_eq!(out, "id: L7C");
+    /// ```
+    pub fn display<T: Convertible>(&self, decimal: T) -> DisplayToken<'_, T> {
+        DisplayToken {
+            converter: self,
+            decimal,
+        }
+    }
+    /// Converts every number of `numbers` and joins the results with `sep`.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.convert_all(&[31u64, 32, 1234], ", "), "Z, 10, 16J");
+    /// ```
+    pub fn convert_all<T: Convertible>(&self, numbers: &[T], sep: &str) -> String {
+        // exact for ASCII corpora, a lower bound for the others
+        let len = numbers
+            .iter()
+            .map(|&number| self.encoded_len(number))
+            .sum::<usize>()
+            + sep.len() * numbers.len().saturating_sub(1);
+        let mut out = String::with_capacity(len);
+        for (i, &number) in numbers.iter().enumerate() {
+            if i > 0 {
+                out.push_str(sep);
+            }
+            self.push_converted(number, &mut out);
+        }
+        out
+    }
+    /// Returns how many characters [`AsciiConverter::convert`] would produce
+    /// for `decimal`, without doing the conversion.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::new(
+    ///     RadixSymbols::Disabled,
+    ///     RadixNumbers::Disabled,
+    ///     RadixLetters::Insensitive,
+    /// ));
+    /// assert_eq!(converter.encoded_len(0u128), 1);
+    /// assert_eq!(converter.encoded_len(123u128), 2);
+    /// ```
+    pub fn encoded_len<T: Convertible>(&self, decimal: T) -> usize {
+        self.unpadded_len(decimal).max(self.min_width)
+    }
+    /// Same as [`AsciiConverter::encoded_len`], without the stored minimum
+    /// width.
+    fn unpadded_len<T: Convertible>(&self, decimal: T) -> usize {
+        let digits = if self.is_empty_zero(decimal) {
+            0
+        } else {
+            BaseConvertIter::new(decimal, self.base).len()
+        };
+        digits + usize::from(self.check.is_some())
+    }
+    /// Same as [`AsciiConverter::convert`], but output is left-padded with
+    /// zero digit (first character of the corpus) until it's at least
+    /// `min_width` characters long. Since padding consists of zero digits,
+    /// padded output decodes to the same number. With
+    /// [`DigitOrder::LeastSignificantFirst`], padding goes after the digits
+    /// (but before the check symbol) instead. Width set with
+    /// [`AsciiConverter::with_min_width`] still applies if it's larger.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::new(
+    ///     RadixSymbols::Disabled,
+    ///     RadixNumbers::Disabled,
+    ///     RadixLetters::Insensitive,
+    /// ));
+    /// assert_eq!(converter.convert_padded(123u128, 5), "aaaet");
+    /// ```
+    pub fn convert_padded<T: Convertible>(&self, decimal: T, min_width: usize) -> String {
+        self.convert_padded_with(decimal, min_width, self.chars[0])
+    }
+    /// Same as [`AsciiConverter::convert_padded`], but pads with `pad`
+    /// instead of the zero digit, for display purposes.
+    ///
+    /// Unless `pad` is the zero digit, padded output doesn't decode to the
+    /// same number anymore: [`AsciiConverter::decode`] either fails or reads
+    /// `pad` as another digit. Padding has to be removed before decoding.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.convert_padded_with(1234u128, 6, '*'), "***16J");
+    /// assert!(converter.decode("***16J").is_err());
+    /// assert_eq!(converter.decode("***16J".trim_start_matches('*')), Ok(1234));
+    /// ```
+    pub fn convert_padded_with<T: Convertible>(
+        &self,
+        decimal: T,
+        min_width: usize,
+        pad: char,
+    ) -> String {
+        let min_width = min_width.max(self.min_width);
+        let mut number = String::with_capacity(min_width);
+        self.push_padded(decimal, min_width, pad, &mut number);
+        number
+    }
+    /// Returns the largest number whose output is at most `width` characters
+    /// long, including the check symbol if there is one. Every number up to
+    /// (and including) this one can be written with
+    /// [`AsciiConverter::convert_fixed`] for that `width`.
+    ///
+    /// Returns `Ok(None)` if every `u128` fits into `width` characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WidthTooShort`] if `width` can't hold any number:
+    /// when it leaves no room for digits next to the check symbol, or when
+    /// it's shorter than [`AsciiConverter::with_min_width`].
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.max_value_for_width(1), Ok(Some(31)));
+    /// assert_eq!(converter.max_value_for_width(8), Ok(Some(32u128.pow(8) - 1)));
+    /// assert_eq!(converter.max_value_for_width(26), Ok(None));
+    ///
+    /// let converter = AsciiConverter::crockford_base32_with_check();
+    /// assert_eq!(converter.max_value_for_width(1), Err(Error::WidthTooShort));
+    /// ```
+    pub fn max_value_for_width(&self, width: usize) -> Result<Option<u128>, Error> {
+        let digits = width
+            .checked_sub(usize::from(self.check.is_some()))
+            .filter(|_| width >= self.min_width)
+            .ok_or(Error::WidthTooShort)?;
+        if digits == 0 {
+            // only zero can be written without digits
+            return if self.zero_as_empty {
+                Ok(Some(0))
+            } else {
+                Err(Error::WidthTooShort)
+            };
+        }
+        // widths beyond `u32` are wider than any `u128` needs
+        let Ok(digits) = u32::try_from(digits) else {
+            return Ok(None);
+        };
+        Ok((self.base() as u128)
+            .checked_pow(digits)
+            .map(|power| power - 1))
+    }
+    /// Same as [`AsciiConverter::convert_padded`], but output is exactly
+    /// `width` characters long, including the check symbol if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if `decimal` doesn't fit into `width`
+    /// characters, see [`AsciiConverter::max_value_for_width`].
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.convert_fixed(1234u128, 6), Ok("00016J".into()));
+    /// assert_eq!(converter.convert_fixed(32u128.pow(6), 6), Err(Error::Overflow));
+    /// ```
+    pub fn convert_fixed<T: Convertible>(&self, decimal: T, width: usize) -> Result<String, Error> {
+        if self.encoded_len(decimal) > width {
+            return Err(Error::Overflow);
+        }
+        Ok(self.convert_padded(decimal, width))
+    }
+    /// Same as [`AsciiConverter::convert`], but output is split into groups
+    /// of `group` characters from the left, separated with `sep`. `group` of
+    /// 0 means no grouping. Use [`AsciiConverter::decode_grouped`] to decode
+    /// grouped output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SeparatorInCorpus`] if `sep` is a character that
+    /// [`AsciiConverter::decode`] would accept.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.convert_grouped(u64::MAX, 4, '-'), Ok("FZZZ-ZZZZ-ZZZZ-Z".into()));
+    /// ```
+    pub fn convert_grouped<T: Convertible>(
+        &self,
+        decimal: T,
+        group: usize,
+        sep: char,
+    ) -> Result<String, Error> {
+        self.check_separator(sep)?;
+        let number = self.convert(decimal);
+        if group == 0 {
+            return Ok(number);
+        }
+        let mut out = String::with_capacity(number.len() * 2);
+        for (i, ch) in number.chars().enumerate() {
+            // grouping starts from the left, so only the last group can be
+            // shorter
+            if i > 0 && i % group == 0 {
+                out.push(sep);
+            }
+            out.push(ch);
+        }
+        Ok(out)
+    }
+    /// Same as [`AsciiConverter::decode`], but `sep` characters are ignored
+    /// wherever they are. This is the inverse of
+    /// [`AsciiConverter::convert_grouped`]. Positions in errors refer to
+    /// `ascii`, including separators.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.decode_grouped("FZZZ-ZZZZ-ZZZZ-Z", '-'), Ok(u64::MAX as u128));
+    /// assert_eq!(converter.decode_grouped("F-ZZZZ-ZZZZ-ZZZZ", '-'), Ok(u64::MAX as u128));
+    /// ```
+    pub fn decode_grouped(&self, ascii: &str, sep: char) -> Result<u128, Error> {
+        self.check_separator(sep)?;
+        let digits: String = ascii.chars().filter(|&ch| ch != sep).collect();
+        match self.decode(&digits) {
+            Err(Error::InvalidChar { ch, pos }) => {
+                // `pos` is an offset in `digits`, find same character in `ascii`
+                let index = digits[..pos].chars().count();
+                let pos = ascii
+                    .char_indices()
+                    .filter(|&(_, ch)| ch != sep)
+                    .nth(index)
+                    .map_or(pos, |(pos, _)| pos);
+                Err(Error::InvalidChar { ch, pos })
+            }
+            result => result,
+        }
+    }
+    /// Refuses separators that `decode` would read as a digit or a check
+    /// symbol.
+    fn check_separator(&self, sep: char) -> Result<(), Error> {
+        if self.is_decoded(sep) {
+            return Err(Error::SeparatorInCorpus(sep));
+        }
+        Ok(())
+    }
+    /// Returns whether `decode` reads `ch` as a digit or a check symbol.
+    fn is_decoded(&self, ch: char) -> bool {
+        let is_check_symbol = self
+            .check
+            .is_some_and(|check| check.matches(0, ch, self).is_some());
+        self.digits.get(ch).is_some() || is_check_symbol
+    }
+    /// Returns the same converter, but [`AsciiConverter::convert_signed`]
+    /// and [`AsciiConverter::decode_signed`] mark negative numbers with
+    /// `sign`, instead of `-`.
+    pub fn with_sign(self, sign: char) -> Self {
+        Self { sign, ..self }
+    }
+    /// Same as [`AsciiConverter::convert`], but for signed numbers. Output of
+    /// a negative number is its absolute value after a sign character (`-`
+    /// unless it's changed with [`AsciiConverter::with_sign`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SignInCorpus`] if the sign character is one that
+    /// [`AsciiConverter::decode`] would accept, since the output would be
+    /// ambiguous.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.convert_signed(-1234), Ok("-16J".into()));
+    /// assert_eq!(converter.convert_signed(1234), Ok("16J".into()));
+    ///
+    /// let converter = AsciiConverter::new(&"aao".parse().unwrap());
+    /// assert_eq!(converter.convert_signed(-1), Err(Error::SignInCorpus('-')));
+    /// ```
+    pub fn convert_signed(&self, decimal: i128) -> Result<String, Error> {
+        if self.is_decoded(self.sign) {
+            return Err(Error::SignInCorpus(self.sign));
+        }
+        let mut number = String::new();
+        if decimal < 0 {
+            number.push(self.sign);
+        }
+        self.push_converted(decimal.unsigned_abs(), &mut number);
+        Ok(number)
+    }
+    /// Does ascii numbers to signed decimal conversion. This is the inverse
+    /// of [`AsciiConverter::convert_signed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SignInCorpus`] same as `convert_signed`, and errors of
+    /// [`AsciiConverter::decode`] otherwise. [`Error::Overflow`] is returned
+    /// for values that don't fit into `i128`.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.decode_signed("-16J"), Ok(-1234));
+    /// assert_eq!(converter.decode_signed("16J"), Ok(1234));
+    /// ```
+    pub fn decode_signed(&self, ascii: &str) -> Result<i128, Error> {
+        if self.is_decoded(self.sign) {
+            return Err(Error::SignInCorpus(self.sign));
+        }
+        match ascii.strip_prefix(self.sign) {
+            Some(digits) => match self.decode(digits) {
+                Ok(number) => 0i128.checked_sub_unsigned(number).ok_or(Error::Overflow),
+                Err(Error::InvalidChar { ch, pos }) => Err(Error::InvalidChar {
+                    ch,
+                    pos: pos + self.sign.len_utf8(),
+                }),
+                Err(err) => Err(err),
+            },
+            None => i128::try_from(self.decode(ascii)?).map_err(|_| Error::Overflow),
+        }
+    }
+    /// Returns an iterator which lazily converts each number of `numbers`,
+    /// same as calling [`AsciiConverter::convert`] on them one by one.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// let ids: Vec<u64> = vec![31, 32, 1234];
+    /// let tokens: Vec<String> = converter.convert_iter(ids).collect();
+    /// assert_eq!(tokens, ["Z", "10", "16J"]);
+    /// ```
+    pub fn convert_iter<I>(&self, numbers: I) -> ConvertIter<'_, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: Convertible,
+    {
+        ConvertIter {
+            converter: self,
+            numbers: numbers.into_iter(),
+        }
+    }
+    /// Does ascii numbers to decimal conversion. This is the inverse of
+    /// [`AsciiConverter::convert`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Empty`] if there are no digits (unless the converter
+    /// is built with [`AsciiConverter::with_zero_as_empty`]),
+    /// [`Error::InvalidChar`] for characters that aren't in the corpus,
+    /// [`Error::Overflow`] if the value doesn't fit into `u128` and
+    /// [`Error::CheckMismatch`] if the converter uses a check symbol that
+    /// doesn't match.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::new(
+    ///     RadixSymbols::Disabled,
+    ///     RadixNumbers::Disabled,
+    ///     RadixLetters::Insensitive,
+    /// ));
+    /// assert_eq!(converter.decode("et"), Ok(123));
+    /// ```
+    pub fn decode(&self, ascii: &str) -> Result<u128, Error> {
+        let (ascii, check) = match (self.check, ascii.char_indices().next_back()) {
+            (Some(check), Some((pos, symbol))) => (&ascii[..pos], Some((check, pos, symbol))),
+            _ => (ascii, None),
+        };
+        // without digits, zero still needs its check symbol
+        if ascii.is_empty() && (!self.zero_as_empty || (self.check.is_some() && check.is_none())) {
+            return Err(Error::Empty);
+        }
+        let base = self.base.get() as u128;
+        let push_digit = |number: u128, (pos, ch): (usize, char)| {
+            let digit = self.digits.get(ch).ok_or(Error::InvalidChar { ch, pos })?;
+            number
+                .checked_mul(base)
+                .and_then(|number| number.checked_add(digit as u128))
+                .ok_or(Error::Overflow)
+        };
+        let number = match self.order {
+            DigitOrder::MostSignificantFirst => ascii.char_indices().try_fold(0, push_digit),
+            DigitOrder::LeastSignificantFirst => ascii.char_indices().rev().try_fold(0, push_digit),
+        }?;
+        if let Some((check, pos, symbol)) = check {
+            match check.matches(number, symbol, self) {
+                Some(true) => {}
+                Some(false) => return Err(Error::CheckMismatch),
+                None => return Err(Error::InvalidChar { ch: symbol, pos }),
+            }
+        }
+        Ok(number)
+    }
+    /// Returns a short token for `input`, by converting its 128-bit FNV-1a
+    /// hash. Same input always gives the same token, across runs and
+    /// platforms, so tokens can be stored and compared later.
+    ///
+    /// FNV-1a isn't a cryptographic hash: it's fast but collisions can be
+    /// found easily, so don't use these tokens where someone could benefit
+    /// from forging one.
+    ///
+    /// Only available with the `hash` feature.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::crockford_base32();
+    /// assert_eq!(converter.short_id(b"hello"), "73W7QXAGM3V57Q109H9DCSTCDK");
+    /// assert_eq!(converter.short_id("hello".as_bytes()), converter.short_id(b"hello"));
+    /// ```
+    #[cfg(feature = "hash")]
+    pub fn short_id(&self, input: &[u8]) -> String {
+        self.convert(fnv1a_128(input))
+    }
+    /// Compares two tokens by their values, so sorting tokens with this
+    /// puts them in numeric order. Ordering tokens by their characters
+    /// doesn't do that unless the corpus is sorted by code point, which
+    /// isn't the case for most corpora.
+    ///
+    /// Values are compared digit by digit, so tokens too long for `u128`
+    /// are compared too. Tokens that can't be decoded (because of an invalid
+    /// character, or because there are no digits) come after every valid
+    /// token. Equal values with different characters (like an uppercase
+    /// token of an insensitive corpus) and invalid tokens are ordered by
+    /// their characters, so tokens are only equal if they are the same
+    /// string. Check symbols aren't verified.
+    ///
+    /// ```
+    /// use asciinum::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// let converter = AsciiConverter::new(&RadixSettings::default());
+    /// // `B` comes before `a` in ASCII, but it's a larger digit here
+    /// assert_eq!(converter.cmp_tokens("a", "B"), Ordering::Less);
+    /// assert_eq!(converter.cmp_tokens("z", "10"), Ordering::Less);
+    ///
+    /// let mut tokens = vec!["10", "B", "a", "z"];
+    /// tokens.sort_by(|a, b| converter.cmp_tokens(a, b));
+    /// assert_eq!(tokens, ["a", "B", "z", "10"]);
+    /// ```
+    pub fn cmp_tokens(&self, a: &str, b: &str) -> Ordering {
+        match (self.token_digits(a), self.token_digits(b)) {
+            (Some(a_digits), Some(b_digits)) => a_digits
+                .len()
+                .cmp(&b_digits.len())
+                .then_with(|| a_digits.cmp(&b_digits)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+        .then_with(|| a.cmp(b))
+    }
+    /// Returns digit values of `token` from the most significant one,
+    /// without leading zeros and the check symbol. Returns `None` if `token`
+    /// can't be decoded.
+    fn token_digits(&self, token: &str) -> Option<Vec<usize>> {
+        let mut chars = token.chars();
+        if self.check.is_some() {
+            chars.next_back();
+        }
+        let mut digits = chars
+            .map(|ch| self.digits.get(ch))
+            .collect::<Option<Vec<usize>>>()?;
+        if digits.is_empty() && !self.zero_as_empty {
+            return None;
+        }
+        if self.order == DigitOrder::LeastSignificantFirst {
+            digits.reverse();
+        }
+        let zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        digits.drain(..zeros);
+        Some(digits)
+    }
+}
+
+impl TryFrom<&str> for AsciiConverter {
+    type Error = Error;
+
+    /// Same as [`AsciiConverter::from_corpus`].
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::try_from("0123456789abcdef")?;
+    /// assert_eq!(converter.convert(255u128), "ff");
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn try_from(corpus: &str) -> Result<Self, Self::Error> {
+        Self::from_corpus(corpus)
+    }
+}
+
+/// 128-bit FNV-1a hash of `bytes`.
+#[cfg(feature = "hash")]
+fn fnv1a_128(bytes: &[u8]) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u128::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// SplitMix64 random number generator, which [`AsciiConverter::shuffled`]
+/// uses. Its output only depends on the seed, so it must not change.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Iterator that converts numbers of another iterator, see
+/// [`AsciiConverter::convert_iter`].
+#[derive(Clone, Debug)]
+pub struct ConvertIter<'a, I> {
+    converter: &'a AsciiConverter,
+    numbers: I,
+}
+
+impl<I> Iterator for ConvertIter<'_, I>
+where
+    I: Iterator,
+    I::Item: Convertible,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.numbers
+            .next()
+            .map(|number| self.converter.convert(number))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.numbers.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for ConvertIter<'_, I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Convertible,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.numbers
+            .next_back()
+            .map(|number| self.converter.convert(number))
+    }
+}
+
+impl<I> ExactSizeIterator for ConvertIter<'_, I>
+where
+    I: ExactSizeIterator,
+    I::Item: Convertible,
+{
+}
+
+impl<I> FusedIterator for ConvertIter<'_, I>
+where
+    I: FusedIterator,
+    I::Item: Convertible,
+{
+}
+
+/// Output of [`AsciiConverter::convert`] which is written while formatting,
+/// see [`AsciiConverter::display`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayToken<'a, T> {
+    converter: &'a AsciiConverter,
+    decimal: T,
+}
+
+impl<T: Convertible> fmt::Display for DisplayToken<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sink = FormatterSink { f, result: Ok(()) };
+        self.converter.push_converted(self.decimal, &mut sink);
+        sink.result
+    }
+}
+
+/// Writes characters into a formatter, and keeps the first error.
+struct FormatterSink<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    result: fmt::Result,
+}
+
+impl Extend<char> for FormatterSink<'_, '_> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, chars: I) {
+        use fmt::Write as _;
+
+        for ch in chars {
+            if self.result.is_err() {
+                return;
+            }
+            self.result = self.f.write_char(ch);
+        }
+    }
+}
+
+/// Bytes of ASCII characters, see [`AsciiConverter::convert_bytes_out`].
+struct AsciiBytes(Vec<u8>);
+
+impl Extend<char> for AsciiBytes {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, chars: I) {
+        self.0.extend(
+            chars
+                .into_iter()
+                .map(|ch| u8::try_from(ch).expect("corpus and check symbols are ASCII")),
+        );
+    }
+}
+
+/// Output of [`AsciiConverter::convert_inline`]. Dereferences to `str`.
+#[derive(Clone)]
+pub struct InlineToken(InlineRepr);
+
+#[derive(Clone)]
+enum InlineRepr {
+    Inline {
+        buf: [u8; InlineToken::CAPACITY],
+        len: usize,
+    },
+    Heap(String),
+}
+
+impl InlineToken {
+    /// Longest output in bytes that is kept on the stack.
+    pub const CAPACITY: usize = 64;
+
+    /// Returns the output as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            InlineRepr::Inline { buf, len } => {
+                core::str::from_utf8(&buf[..*len]).expect("only whole chars are written")
+            }
+            InlineRepr::Heap(string) => string,
+        }
+    }
+    /// Returns whether the output is kept on the stack, instead of being
+    /// allocated.
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, InlineRepr::Inline { .. })
+    }
+}
+
+impl core::ops::Deref for InlineToken {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for InlineToken {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for InlineToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for InlineToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for InlineToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for InlineToken {}
+
+impl PartialEq<str> for InlineToken {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for InlineToken {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<InlineToken> for String {
+    fn from(token: InlineToken) -> Self {
+        match token.0 {
+            InlineRepr::Inline { .. } => token.as_str().to_owned(),
+            InlineRepr::Heap(string) => string,
+        }
+    }
+}
+
+/// Fixed buffer that [`AsciiConverter::convert_inline`] writes into. Once a
+/// character doesn't fit, written characters move to a `String` and the rest
+/// go there too.
+struct InlineBuf {
+    buf: [u8; InlineToken::CAPACITY],
+    len: usize,
+    spilled: Option<String>,
+}
+
+impl Default for InlineBuf {
+    fn default() -> Self {
+        Self {
+            buf: [0; InlineToken::CAPACITY],
+            len: 0,
+            spilled: None,
+        }
+    }
+}
+
+impl Extend<char> for InlineBuf {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, chars: I) {
+        for ch in chars {
+            if let Some(string) = &mut self.spilled {
+                string.push(ch);
+            } else if self.len + ch.len_utf8() > InlineToken::CAPACITY {
+                let written = core::str::from_utf8(&self.buf[..self.len])
+                    .expect("only whole chars are written");
+                let mut string = String::with_capacity(2 * InlineToken::CAPACITY);
+                string.push_str(written);
+                string.push(ch);
+                self.spilled = Some(string);
+            } else {
+                self.len += ch.encode_utf8(&mut self.buf[self.len..]).len();
+            }
+        }
+    }
+}
+
+pub trait TrimAsciiControlCharacters {
+    /// Returns a slice with leading and trailing ASCII control bytes removed.
+    /// It's implemented for byte slices and strings.
+    ///
+    /// 'Control bytes' refers to the definition used by
+    /// [`u8::is_ascii_control`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// assert_eq!(
+    ///     b"\r hello world\n ".trim_ascii_control(),
+    ///     b" hello world\n "
+    /// );
+    /// assert_eq!(b"  ".trim_ascii_control(), b"  ");
+    /// assert_eq!(b"".trim_ascii_control(), b"");
+    /// assert_eq!("\tmerhaba dünya\r\n".trim_ascii_control(), "merhaba dünya");
+    /// ```
+    fn trim_ascii_control(&self) -> &Self;
+    /// Returns a slice with leading ASCII control bytes removed.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// assert_eq!(b"\r\nhello\r\n".trim_ascii_control_start(), b"hello\r\n");
+    /// assert_eq!("\r\nhello\r\n".trim_ascii_control_start(), "hello\r\n");
+    /// ```
+    fn trim_ascii_control_start(&self) -> &Self;
+    /// Returns a slice with trailing ASCII control bytes removed.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// assert_eq!(b"\r\nhello\r\n".trim_ascii_control_end(), b"\r\nhello");
+    /// assert_eq!("\r\nhello\r\n".trim_ascii_control_end(), "\r\nhello");
+    /// ```
+    fn trim_ascii_control_end(&self) -> &Self;
+    /// Same as [`TrimAsciiControlCharacters::trim_ascii_control`], but
+    /// returns an owned copy if anything is trimmed, and borrows `self`
+    /// otherwise. Callers that need an owned result only pay for the copy
+    /// when there are control bytes to remove, and can tell whether there
+    /// were any.
+    ///
+    /// ```
+    /// use asciinum::*;
+    /// use std::borrow::Cow;
+    ///
+    /// assert_eq!("hello".trim_ascii_control_cow(), Cow::Borrowed("hello"));
+    /// assert_eq!(
+    ///     "hello\r\n".trim_ascii_control_cow(),
+    ///     Cow::<str>::Owned("hello".into())
+    /// );
+    /// ```
+    fn trim_ascii_control_cow(&self) -> Cow<'_, Self>
+    where
+        Self: ToOwned;
+}
+
+impl TrimAsciiControlCharacters for [u8] {
+    fn trim_ascii_control(&self) -> &[u8] {
+        self.trim_ascii_control_start().trim_ascii_control_end()
+    }
+    fn trim_ascii_control_start(&self) -> &[u8] {
+        match self.iter().position(|x| !x.is_ascii_control()) {
+            Some(from) => &self[from..],
+            None => &self[0..0],
+        }
+    }
+    fn trim_ascii_control_end(&self) -> &[u8] {
+        match self.iter().rposition(|x| !x.is_ascii_control()) {
+            Some(to) => &self[..=to],
+            None => &self[0..0],
+        }
+    }
+    fn trim_ascii_control_cow(&self) -> Cow<'_, [u8]> {
+        let trimmed = self.trim_ascii_control();
+        if trimmed.len() == self.len() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(trimmed.to_owned())
+        }
+    }
+}
+
+// ASCII bytes are never a part of a multibyte sequence, so trimming them leaves
+// valid UTF-8 behind
+impl TrimAsciiControlCharacters for str {
+    fn trim_ascii_control(&self) -> &str {
+        self.trim_ascii_control_start().trim_ascii_control_end()
+    }
+    fn trim_ascii_control_start(&self) -> &str {
+        core::str::from_utf8(self.as_bytes().trim_ascii_control_start())
+            .expect("trimming ASCII bytes keeps UTF-8 valid")
+    }
+    fn trim_ascii_control_end(&self) -> &str {
+        core::str::from_utf8(self.as_bytes().trim_ascii_control_end())
+            .expect("trimming ASCII bytes keeps UTF-8 valid")
+    }
+    fn trim_ascii_control_cow(&self) -> Cow<'_, str> {
+        let trimmed = self.trim_ascii_control();
+        if trimmed.len() == self.len() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(trimmed.to_owned())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radix_settings_from_str() {
+        assert_eq!(
+            "a".parse::<RadixSettings>(),
+            Err(RadixParseError::WrongLength)
+        );
+        assert_eq!(
+            "aa".parse::<RadixSettings>(),
+            Err(RadixParseError::WrongLength)
+        );
+        assert_eq!(
+            "aaix".parse::<RadixSettings>(),
+            Err(RadixParseError::WrongLength)
+        );
+
+        assert_eq!(
+            "asd".parse::<RadixSettings>(),
+            Err(RadixParseError::BadNumbers('s'))
+        );
+        assert_eq!(
+            "efg".parse::<RadixSettings>(),
+            Err(RadixParseError::BadSymbols('e'))
+        );
+        assert!("haha\r\nhehe".parse::<RadixSettings>().is_err());
+
+        assert_eq!(
+            "iua".parse::<RadixSettings>(),
+            Err(RadixParseError::BadSymbols('i'))
+        );
+        assert_eq!(
+            "suu".parse::<RadixSettings>(),
+            Err(RadixParseError::BadSymbols('s'))
+        );
+        assert_eq!(
+            "oud".parse::<RadixSettings>(),
+            Err(RadixParseError::BadSymbols('o'))
+        );
+        assert_eq!(
+            "dax".parse::<RadixSettings>(),
+            Err(RadixParseError::BadLetters('x'))
+        );
+
+        assert_eq!(
+            "aai".parse::<RadixSettings>().unwrap(),
+            RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::All,
+                RadixLetters::Insensitive
+            )
+        );
+        assert_eq!(
+            "udi".parse::<RadixSettings>().unwrap(),
+            RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::Disabled,
+                RadixLetters::Insensitive
+            )
+        );
+        assert_eq!(
+            "das".parse::<RadixSettings>().unwrap(),
+            RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::All,
+                RadixLetters::Sensitive
+            )
+        );
+        assert_eq!(
+            "ado".parse::<RadixSettings>().unwrap(),
+            RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::Disabled,
+                RadixLetters::SensitiveOrdered
+            )
+        );
+    }
+
+    #[test]
+    fn test_radix_parse_error_display() {
+        // these are shown by the CLI, so they are kept the same as before
+        // RadixParseError existed
+        assert_eq!(
+            RadixParseError::WrongLength.to_string(),
+            "must be 3 characters long"
+        );
+        assert_eq!(
+            RadixParseError::BadSymbols('x').to_string(),
+            "first character of radix arg must be one of these: {a,u,d}"
+        );
+        assert_eq!(
+            RadixParseError::BadNumbers('x').to_string(),
+            "second character of radix arg must be one of these: {a,d}"
+        );
+        assert_eq!(
+            RadixParseError::BadLetters('x').to_string(),
+            "third character of radix arg must be one of these: {i,s,o}"
+        );
+        assert_eq!(
+            RadixParseError::TooShortCorpus.to_string(),
+            "radix arg must give at least 2 distinct characters"
+        );
+        let err: Box<dyn core::error::Error> = Box::new(RadixParseError::WrongLength);
+        assert_eq!(err.to_string(), "must be 3 characters long");
+    }
+
+    #[test]
+    fn test_radix_settings_validated() {
+        // smallest corpus that RADIXOPT can give
+        let settings: RadixSettings = "ddi".parse().expect("valid RADIXOPT");
+        assert_eq!(settings.corpus().len(), 26);
+        assert_eq!(settings.clone().validated(), Ok(settings));
+
+        let custom = |letters: &str| {
+            RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Disabled,
+                RadixLetters::Custom(letters.into()),
+            )
+        };
+        assert_eq!(custom("xy").validated(), Ok(custom("xy")));
+        assert_eq!(custom("").validated(), Err(RadixParseError::TooShortCorpus));
+        assert_eq!(
+            custom("x").validated(),
+            Err(RadixParseError::TooShortCorpus)
+        );
+    }
+
+    #[test]
+    fn test_radix_settings_default() {
+        assert_eq!(RadixSettings::default(), "dao".parse().unwrap());
+        assert_eq!(RadixSettings::default().to_string(), "dao");
+    }
+
+    #[test]
+    fn test_radix_settings_builder() {
+        assert_eq!(RadixSettings::builder().build(), RadixSettings::default());
+        assert_eq!(
+            RadixSettings::builder()
+                .symbols(RadixSymbols::UnixSafe)
+                .build(),
+            RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::All,
+                RadixLetters::SensitiveOrdered
+            )
+        );
+        assert_eq!(
+            RadixSettings::builder()
+                .letters(RadixLetters::Sensitive)
+                .numbers(RadixNumbers::Disabled)
+                .symbols(RadixSymbols::All)
+                .build(),
+            RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::Disabled,
+                RadixLetters::Sensitive
+            )
+        );
+    }
+
+    #[test]
+    fn test_custom_letters() {
+        let settings = RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::All,
+            RadixLetters::Custom("aeiou".into()),
+        );
+        assert_eq!(settings.corpus(), "0123456789aeiou");
+        assert_eq!(settings.to_string(), "da[aeiou]");
+        let converter = AsciiConverter::try_new(&settings).expect("valid corpus");
+        assert_eq!(converter.convert(14u128), "u");
+        assert_eq!(converter.convert(15u128), "10");
+        assert_eq!(converter.decode("u0"), Ok(210));
+
+        let settings = RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::Disabled,
+            RadixLetters::Custom("çğıöşü".into()),
+        );
+        let converter = AsciiConverter::try_new(&settings).expect("valid corpus");
+        assert_eq!(converter.convert(6u128), "ğç");
+
+        // empty corpus is refused up front, instead of panicking on first use
+        let empty = RadixSettings::new(
+            RadixSymbols::Custom(String::new()),
+            RadixNumbers::Disabled,
+            RadixLetters::Custom(String::new()),
+        );
+        assert_eq!(empty.corpus(), "");
+        assert_eq!(AsciiConverter::try_new(&empty), Err(Error::TooShortCorpus));
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Disabled,
+                RadixLetters::Custom("x".into()),
+            )),
+            Err(Error::TooShortCorpus)
+        );
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Disabled,
+                RadixLetters::Custom("abcb".into()),
+            )),
+            Err(Error::DuplicateChar('b'))
+        );
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::All,
+                RadixLetters::Custom("xyz?".into()),
+            )),
+            Err(Error::DuplicateChar('?'))
+        );
+    }
+
+    #[test]
+    fn test_custom_numbers() {
+        let settings = RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::Custom("٠١٢٣٤٥٦٧٨٩".into()),
+            RadixLetters::Custom("ab".into()),
+        );
+        // numbers keep their position between symbols and letters
+        assert_eq!(settings.corpus(), "٠١٢٣٤٥٦٧٨٩ab");
+        assert_eq!(settings.to_string(), "d[٠١٢٣٤٥٦٧٨٩][ab]");
+        let converter = AsciiConverter::try_new(&settings).expect("valid corpus");
+        assert_eq!(converter.convert(9u128), "٩");
+        assert_eq!(converter.convert(11u128), "b");
+        assert_eq!(converter.convert(12u128), "١٠");
+        assert_eq!(converter.decode("١٠"), Ok(12));
+
+        // a subset of numbers
+        let settings = RadixSettings::new(
+            RadixSymbols::Custom("+".into()),
+            RadixNumbers::Custom("01".into()),
+            RadixLetters::Insensitive,
+        );
+        assert_eq!(settings.corpus(), "+01abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(settings.to_string(), "[+][01]i");
+
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Custom("0110".into()),
+                RadixLetters::Insensitive,
+            )),
+            Err(Error::DuplicateChar('1'))
+        );
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Custom("0a".into()),
+                RadixLetters::Insensitive,
+            )),
+            Err(Error::DuplicateChar('a'))
+        );
+    }
+
+    #[test]
+    fn test_custom_symbols() {
+        let settings = RadixSettings::new(
+            RadixSymbols::Custom("-_.~".into()),
+            RadixNumbers::All,
+            RadixLetters::Insensitive,
+        );
+        // symbols come first, then numbers, then letters
+        assert_eq!(
+            settings.corpus(),
+            "-_.~0123456789abcdefghijklmnopqrstuvwxyz"
+        );
+        assert_eq!(settings.to_string(), "[-_.~]ai");
+        let converter = AsciiConverter::try_new(&settings).expect("valid corpus");
+        assert_eq!(converter.convert(0u128), "-");
+        assert_eq!(converter.convert(4u128), "0");
+        assert_eq!(converter.convert(40u128), "_-");
+        assert_eq!(converter.decode("_-"), Ok(40));
+
+        let settings = RadixSettings::new(
+            RadixSymbols::Custom("+".into()),
+            RadixNumbers::Disabled,
+            RadixLetters::Custom("xyz".into()),
+        );
+        assert_eq!(settings.corpus(), "+xyz");
+        assert_eq!(settings.to_string(), "[+]d[xyz]");
+
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::Custom("!?!".into()),
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            )),
+            Err(Error::DuplicateChar('!'))
+        );
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::Custom("#7".into()),
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            )),
+            Err(Error::DuplicateChar('7'))
+        );
+        // insensitive letters are decoded regardless of their case
+        assert_eq!(
+            AsciiConverter::try_new(&RadixSettings::new(
+                RadixSymbols::Custom("+Q".into()),
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            )),
+            Err(Error::DuplicateChar('Q'))
+        );
+        let converter = AsciiConverter::try_new(&RadixSettings::new(
+            RadixSymbols::Custom("+Q".into()),
+            RadixNumbers::All,
+            RadixLetters::Custom("pqr".into()),
+        ))
+        .expect("valid corpus");
+        assert_eq!(converter.decode("Q"), Ok(1));
+        assert_eq!(converter.decode("q"), Ok(13));
+    }
+
+    #[test]
+    fn test_radix_settings_order() {
+        let settings = RadixSettings::new(
+            RadixSymbols::Custom("+".into()),
+            RadixNumbers::All,
+            RadixLetters::Insensitive,
+        );
+        assert_eq!(settings.order, DEFAULT_ORDER);
+        assert_eq!(settings.corpus(), "+0123456789abcdefghijklmnopqrstuvwxyz");
+
+        let settings = RadixSettings {
+            order: [
+                RadixComponent::Letters,
+                RadixComponent::Numbers,
+                RadixComponent::Symbols,
+            ],
+            ..settings
+        };
+        assert_eq!(settings.corpus(), "abcdefghijklmnopqrstuvwxyz0123456789+");
+        let converter = AsciiConverter::new(&settings);
+        assert_eq!(converter.convert(0u128), "a");
+        assert_eq!(converter.convert(36u128), "+");
+        assert_eq!(converter.convert(37u128), "ba");
+        assert_eq!(converter.decode("ba"), Ok(37));
+
+        // numbers first is the default for RADIXOPT without symbols, and
+        // letters first changes digit values of every character
+        let numbers_first = RadixSettings::builder()
+            .order([
+                RadixComponent::Numbers,
+                RadixComponent::Letters,
+                RadixComponent::Symbols,
+            ])
+            .build();
+        assert_eq!(numbers_first.corpus(), RadixSettings::default().corpus());
+        let letters_first = RadixSettings::builder()
+            .order([
+                RadixComponent::Letters,
+                RadixComponent::Numbers,
+                RadixComponent::Symbols,
+            ])
+            .build();
+        assert_eq!(
+            letters_first.corpus(),
+            "AaBbCcDdEeFfGgHhIiJjKkLlMmNnOoPpQqRrSsTtUuVvWwXxYyZz0123456789"
+        );
+        assert_eq!(AsciiConverter::new(&letters_first).convert(61u128), "9");
+
+        // order isn't a part of RADIXOPT
+        assert_eq!(letters_first.to_string(), "dao");
+        assert_ne!(letters_first, RadixSettings::default());
+
+        // repeated components make the corpus invalid
+        let settings = RadixSettings::builder()
+            .order([
+                RadixComponent::Numbers,
+                RadixComponent::Numbers,
+                RadixComponent::Letters,
+            ])
+            .build();
+        assert_eq!(
+            AsciiConverter::try_new(&settings),
+            Err(Error::DuplicateChar('0'))
+        );
+    }
+
+    #[test]
+    fn test_corpus_concatenation() {
+        let orders = [
+            DEFAULT_ORDER,
+            [
+                RadixComponent::Letters,
+                RadixComponent::Symbols,
+                RadixComponent::Numbers,
+            ],
+        ];
+        for symbols in ["a", "u", "d"] {
+            for numbers in ["a", "d"] {
+                for letters in ["i", "s", "o"] {
+                    let settings: RadixSettings = [symbols, numbers, letters]
+                        .concat()
+                        .parse()
+                        .expect("valid RADIXOPT");
+                    for order in orders {
+                        let settings = RadixSettings {
+                            order,
+                            ..settings.clone()
+                        };
+                        let corpus = settings.corpus();
+                        assert_eq!(
+                            corpus.as_bytes(),
+                            order.map(|c| settings.component(c)).concat().as_bytes()
+                        );
+                        assert_eq!(corpus.capacity(), corpus.len());
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            RadixSettings::default().corpus(),
+            "0123456789AaBbCcDdEeFfGgHhIiJjKkLlMmNnOoPpQqRrSsTtUuVvWwXxYyZz"
+        );
+    }
+
+    #[test]
+    fn test_preset_len() {
+        const INSENSITIVE: Option<usize> = RadixLetters::Insensitive.preset_len();
+        assert_eq!(INSENSITIVE, Some(26));
+        assert_eq!(RadixNumbers::All.preset_len(), Some(10));
+        assert_eq!(RadixSymbols::All.preset_len(), Some(32));
+
+        let orders = [
+            DEFAULT_ORDER,
+            [
+                RadixComponent::Numbers,
+                RadixComponent::Letters,
+                RadixComponent::Letters,
+            ],
+        ];
+        for symbols in ["a", "u", "d"] {
+            for numbers in ["a", "d"] {
+                for letters in ["i", "s", "o"] {
+                    let settings: RadixSettings = [symbols, numbers, letters]
+                        .concat()
+                        .parse()
+                        .expect("valid RADIXOPT");
+                    for order in orders {
+                        let settings = RadixSettings {
+                            order,
+                            ..settings.clone()
+                        };
+                        let corpus = settings.corpus();
+                        assert_eq!(settings.preset_len(), Some(corpus.len()));
+                        assert_eq!(settings.preset_len(), Some(corpus.chars().count()));
+                    }
+                    let converter = AsciiConverter::new(&settings);
+                    assert_eq!(settings.preset_len(), Some(converter.base()));
+                }
+            }
+        }
+
+        let custom = RadixSettings::builder()
+            .numbers(RadixNumbers::Custom("01".into()))
+            .build();
+        assert_eq!(custom.numbers.preset_len(), None);
+        assert_eq!(custom.preset_len(), None);
+        // unused custom components don't matter
+        let settings = RadixSettings {
+            order: [RadixComponent::Letters; 3],
+            ..custom
+        };
+        assert_eq!(settings.preset_len(), Some(3 * 52));
+    }
+
+    #[test]
+    fn test_radix_settings_display() {
+        assert_eq!(
+            RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::All,
+                RadixLetters::SensitiveOrdered
+            )
+            .to_string(),
+            "dao"
+        );
+        for symbols in [
+            RadixSymbols::All,
+            RadixSymbols::UnixSafe,
+            RadixSymbols::Disabled,
+        ] {
+            for numbers in [RadixNumbers::All, RadixNumbers::Disabled] {
+                for letters in [
+                    RadixLetters::Insensitive,
+                    RadixLetters::Sensitive,
+                    RadixLetters::SensitiveOrdered,
+                ] {
+                    let settings = RadixSettings::new(symbols.clone(), numbers.clone(), letters);
+                    assert_eq!(settings.to_string().parse(), Ok(settings));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_base_convert_iter() {
+        assert_eq!(
+            BaseConvertIter::new(0u128, NonZeroUsize::new(2).expect("not zero"))
+                .collect::<Vec<usize>>(),
+            vec![0]
+        );
+        assert_eq!(
+            BaseConvertIter::new(5u128, NonZeroUsize::new(1000).expect("not zero"))
+                .collect::<Vec<usize>>(),
+            vec![5]
+        );
+        assert_eq!(
+            BaseConvertIter::new(123456u128, NonZeroUsize::new(62).expect("not zero"))
+                .collect::<Vec<usize>>(),
+            vec![14, 7, 32]
+        );
+        assert_eq!(
+            BaseConvertIter::new(837u128, NonZeroUsize::new(2).expect("not zero"))
+                .collect::<Vec<usize>>(),
+            vec![1, 0, 1, 0, 0, 0, 1, 0, 1, 1]
+        );
+        assert_eq!(
+            BaseConvertIter::new(u128::MAX, NonZeroUsize::new(19209).expect("not zero"))
+                .collect::<Vec<usize>>(),
+            vec![3, 8970, 6739, 1611, 15517, 3461, 285, 18953, 18356]
+        );
+    }
+
+    #[test]
+    fn test_base_convert_iter_try_new() {
+        assert_eq!(BaseConvertIter::try_new(0u128, NonZeroUsize::MIN), None);
+        assert_eq!(BaseConvertIter::try_new(u8::MAX, NonZeroUsize::MIN), None);
+        for base in [2, 10, 62, 1000] {
+            let base = NonZeroUsize::new(base).expect("not zero");
+            assert_eq!(
+                BaseConvertIter::try_new(123456u128, base),
+                Some(BaseConvertIter::new(123456u128, base))
+            );
+        }
+        let panic = std::panic::catch_unwind(|| BaseConvertIter::new(5u32, NonZeroUsize::MIN));
+        assert!(panic.is_err());
+    }
+
+    #[test]
+    fn test_base_convert_iter_rev() {
+        assert_eq!(
+            BaseConvertIter::new(0u128, NonZeroUsize::new(2).expect("not zero"))
+                .rev()
+                .collect::<Vec<usize>>(),
+            vec![0]
+        );
+        assert_eq!(
+            BaseConvertIter::new(123456u128, NonZeroUsize::new(62).expect("not zero"))
+                .rev()
+                .collect::<Vec<usize>>(),
+            vec![32, 7, 14]
+        );
+        assert_eq!(
+            BaseConvertIter::new(837u128, NonZeroUsize::new(2).expect("not zero"))
+                .rev()
+                .collect::<Vec<usize>>(),
+            vec![1, 1, 0, 1, 0, 0, 0, 1, 0, 1]
+        );
+        assert_eq!(
+            BaseConvertIter::new(u128::MAX, NonZeroUsize::new(19209).expect("not zero"))
+                .rev()
+                .collect::<Vec<usize>>(),
+            vec![18356, 18953, 285, 3461, 15517, 1611, 6739, 8970, 3]
+        );
+        assert_eq!(
+            BaseConvertIter::new(u128::MAX, NonZeroUsize::new(2).expect("not zero"))
+                .rev()
+                .collect::<Vec<usize>>(),
+            vec![1; 128]
+        );
+
+        // taking digits from both ends
+        let mut iter = BaseConvertIter::new(123456u128, NonZeroUsize::new(10).expect("not zero"));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next(), Some(6));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
+        // zeros in the middle are still yielded
+        let mut iter = BaseConvertIter::new(10001u128, NonZeroUsize::new(10).expect("not zero"));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next_back(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.collect::<Vec<usize>>(), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_base_convert_iter_len() {
+        for (number, base) in [
+            (0, 2),
+            (1, 2),
+            (5, 1000),
+            (999, 1000),
+            (1000, 1000),
+            (837, 2),
+            (123456, 62),
+            (u128::MAX, 2),
+            (u128::MAX, 19209),
+        ] {
+            let mut iter = BaseConvertIter::new(number, NonZeroUsize::new(base).expect("not zero"));
+            let mut len = iter.len();
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+            assert_eq!(len, iter.collect::<Vec<usize>>().len());
+            while iter.next().is_some() {
+                len -= 1;
+                assert_eq!(iter.len(), len);
+            }
+            assert_eq!(iter.len(), 0);
+
+            iter = BaseConvertIter::new(number, NonZeroUsize::new(base).expect("not zero"));
+            let mut len = iter.len();
+            while iter.next_back().is_some() {
+                len -= 1;
+                assert_eq!(iter.len(), len);
+            }
+            assert_eq!(iter.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_base_convert_iter_fused() {
+        let mut iter = BaseConvertIter::new(62u128, NonZeroUsize::new(62).expect("not zero"));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        let mut iter = BaseConvertIter::new(0u128, NonZeroUsize::new(2).expect("not zero")).fuse();
+        assert_eq!(iter.next(), Some(0));
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn test_convertible() {
+        fn check<T: Convertible + Into<u128>>(number: T) {
+            for base in [2, 10, 62, 255, 256, 257, 19209, usize::MAX] {
+                let base = NonZeroUsize::new(base).expect("not zero");
+                assert_eq!(
+                    BaseConvertIter::new(number, base).collect::<Vec<usize>>(),
+                    BaseConvertIter::new(number.into(), base).collect::<Vec<usize>>(),
+                );
+                assert_eq!(
+                    BaseConvertIter::new(number, base)
+                        .rev()
+                        .collect::<Vec<usize>>(),
+                    BaseConvertIter::new(number.into(), base)
+                        .rev()
+                        .collect::<Vec<usize>>(),
+                );
+            }
+        }
+        for number in [0, 1, 9, 10, 255] {
+            check(number as u8);
+            check(number as u16);
+            check(number as u32);
+            check(number as u64);
+        }
+        check(u8::MAX);
+        check(u16::MAX);
+        check(u32::MAX);
+        check(u64::MAX);
+
+        let converter = AsciiConverter::crockford_base32_with_check();
+        assert_eq!(converter.convert(1234u16), "16JD");
+        assert_eq!(
+            converter.convert(u64::MAX),
+            converter.convert(u64::MAX as u128)
+        );
+        assert_eq!(converter.encoded_len(u32::MAX), 8);
+        assert_eq!(converter.convert_padded(5u8, 3), "055");
+    }
+
+    #[test]
+    fn test_convert_to_ascii() {
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Disabled,
+                RadixLetters::Insensitive,
+            ))
+            .convert(0u128),
+            "a"
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            ))
+            .convert(0u128),
+            "0"
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            ))
+            .convert(0u128),
+            "!"
+        );
+
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            ))
+            .convert(u128::MAX),
+            r##"")+'4/yf`dygv?{w*kdvnj"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            ))
+            .convert(u128::MAX),
+            r##""4{173{}g^z'ikw8_<,~g@"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::All,
+                RadixLetters::Insensitive,
+            ))
+            .convert(u128::MAX),
+            r##"f5lxx1zz5pnorynqglhzmsp33"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::Disabled,
+                RadixLetters::Insensitive,
+            ))
+            .convert(u128::MAX),
+            r##"~d{gxl\c&<]zql%l/"t@>v"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::Disabled,
+                RadixLetters::Insensitive,
+            ))
+            .convert(u128::MAX),
+            r##"oa-q&ov]c>q%`n:?[wd'*$"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Disabled,
+                RadixLetters::Insensitive,
+            ))
+            .convert(u128::MAX),
+            r##"cdhefomrsrxetmsvhtomcungjkbv"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::All,
+                RadixLetters::Sensitive,
+            ))
+            .convert(u128::MAX),
+            r##",#7zGM_d_e&[bar**m,."##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::All,
+                RadixLetters::Sensitive,
+            ))
+            .convert(u128::MAX),
+            r##".GB;(nA-8hN0iu?pLo4c"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::All,
+                RadixLetters::Sensitive,
+            ))
+            .convert(u128::MAX),
+            r##"7n42DGM5Tflk9n8mt7Fhc7"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::Disabled,
+                RadixLetters::Sensitive,
+            ))
+            .convert(u128::MAX),
+            r##""*EwscH.T$Oa?x^]GS@f$"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::Disabled,
+                RadixLetters::Sensitive,
+            ))
+            .convert(u128::MAX),
+            r##""D`<#<C\nsD>`T%lg._?T"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Disabled,
+                RadixLetters::Sensitive,
+            ))
+            .convert(u128::MAX),
+            r##"GBIWTpZqojFGQPQPXtvbJAv"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::All,
+                RadixLetters::SensitiveOrdered,
+            ))
+            .convert(u128::MAX),
+            r##",#7zDG_o_P&[nNv**T,."##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::All,
+                RadixLetters::SensitiveOrdered,
+            ))
+            .convert(u128::MAX),
+            r##".Da;(tA-8qg0RX?ufU4O"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::All,
+                RadixLetters::SensitiveOrdered,
+            ))
+            .convert(u128::MAX),
+            r##"7t42bDG5jpsS9t8Tw7cqO7"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::All,
+                RadixNumbers::Disabled,
+                RadixLetters::SensitiveOrdered,
+            ))
+            .convert(u128::MAX),
+            r##""*CYWOd.j$HN?y^]DJ@p$"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::UnixSafe,
+                RadixNumbers::Disabled,
+                RadixLetters::SensitiveOrdered,
+            ))
+            .convert(u128::MAX),
+            r##""b`<#<B\tWb>`j%sQ._?j"##
+        );
+        assert_eq!(
+            AsciiConverter::new(&RadixSettings::new(
+                RadixSymbols::Disabled,
+                RadixNumbers::Disabled,
+                RadixLetters::SensitiveOrdered,
+            ))
+            .convert(u128::MAX),
+            r##"DaELjumVUrcDIhIhlwxneAx"##
+        );
+    }
+
+    #[test]
+    fn test_from_corpus() {
+        assert_eq!(AsciiConverter::from_corpus(""), Err(Error::TooShortCorpus));
+        assert_eq!(AsciiConverter::from_corpus("a"), Err(Error::TooShortCorpus));
+
+        assert_eq!(
+            AsciiConverter::from_corpus("aa"),
+            Err(Error::DuplicateChar('a'))
+        );
+        assert_eq!(
+            AsciiConverter::from_corpus("0123456789abcdeb"),
+            Err(Error::DuplicateChar('b'))
+        );
+        assert_eq!(
+            AsciiConverter::from_corpus("🦀🐍🦀"),
+            Err(Error::DuplicateChar('🦀'))
+        );
+
+        let converter = AsciiConverter::from_corpus("01").expect("valid corpus");
+        assert_eq!(converter.convert(0u128), "0");
+        assert_eq!(converter.convert(837u128), "1101000101");
+
+        let converter =
+            AsciiConverter::from_corpus("0123456789ABCDEFGHJKMNPQRSTVWXYZ").expect("valid corpus");
+        assert_eq!(converter.convert(1234u128), "16J");
+        assert_eq!(converter.decode("16J"), Ok(1234));
+
+        // building from settings and building from their corpus are the same thing
+        let settings = RadixSettings::new(
+            RadixSymbols::UnixSafe,
+            RadixNumbers::All,
+            RadixLetters::Sensitive,
+        );
+        assert_eq!(
+            AsciiConverter::from_corpus(&settings.corpus()),
+            Ok(AsciiConverter::new(&settings))
+        );
+    }
+
+    #[test]
+    fn test_converter_equality() {
+        use std::{collections::HashSet, hash::BuildHasher};
+
+        let hasher = std::hash::RandomState::new();
+        let same = [
+            AsciiConverter::new(&RadixSettings::default()),
+            AsciiConverter::new(&"dao".parse().expect("valid RADIXOPT")),
+            AsciiConverter::new(
+                &RadixSettings::builder()
+                    .letters(RadixLetters::Custom(LETTERS_ORDERED.into()))
+                    .build(),
+            ),
+            AsciiConverter::from_corpus(&RadixSettings::default().corpus()).expect("valid corpus"),
+            AsciiConverter::try_from(
+                "0123456789AaBbCcDdEeFfGgHhIiJjKkLlMmNnOoPpQqRrSsTtUuVvWwXxYyZz",
+            )
+            .expect("valid corpus"),
+        ];
+        for converter in &same {
+            assert_eq!(converter, &same[0]);
+            assert_eq!(hasher.hash_one(converter), hasher.hash_one(&same[0]));
+        }
+        assert_eq!(
+            AsciiConverter::base58(),
+            AsciiConverter::from_corpus(CORPUS_BASE58).expect("valid corpus")
+        );
+
+        // options are part of the key, the corpus alone isn't
+        let options = [
+            same[0].clone().with_checksum(),
+            same[0].clone().with_min_width(4),
+            same[0].clone().with_sign('~'),
+            same[0]
+                .clone()
+                .with_digit_order(DigitOrder::LeastSignificantFirst),
+        ];
+        for converter in &options {
+            assert_eq!(converter.corpus(), same[0].corpus());
+            assert_ne!(converter, &same[0]);
+        }
+        let unique: HashSet<_> = same.into_iter().chain(options).collect();
+        assert_eq!(unique.len(), 5);
+        // insensitive letters decode uppercase letters too, a plain corpus doesn't
+        let settings: RadixSettings = "ddi".parse().expect("valid RADIXOPT");
+        assert_ne!(
+            AsciiConverter::new(&settings),
+            AsciiConverter::from_corpus(&settings.corpus()).expect("valid corpus")
+        );
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let converter = AsciiConverter::try_from("0123456789abcdef").expect("valid corpus");
+        assert_eq!(converter.convert(48879u128), "beef");
+        assert_eq!(converter.decode("beef"), Ok(48879));
+        assert_eq!(
+            AsciiConverter::try_from("0123456789abcdea"),
+            Err(Error::DuplicateChar('a'))
+        );
+        assert_eq!(AsciiConverter::try_from("x"), Err(Error::TooShortCorpus));
+    }
+
+    #[test]
+    fn test_shuffled() {
+        // reference output of SplitMix64
+        assert_eq!(SplitMix64(0).next_u64(), 0xe220a8397b1dcdaf);
+
+        let base = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+        let converter = AsciiConverter::shuffled(base, 42).expect("valid corpus");
+        assert_eq!(converter.corpus(), "2P73AH9M1TE0GBV5CR4XZSYD8QF6KJWN");
+        assert_eq!(
+            AsciiConverter::shuffled(base, 0)
+                .expect("valid corpus")
+                .corpus(),
+            "DH7TNEJ1M8GXA62P9Y034R5BZWCQVKSF"
+        );
+        assert_eq!(
+            AsciiConverter::shuffled(base, 42),
+            AsciiConverter::shuffled(base, 42)
+        );
+        assert_ne!(
+            AsciiConverter::shuffled(base, 42),
+            AsciiConverter::shuffled(base, 43)
+        );
+
+        // same characters, in another order
+        let mut chars: Vec<char> = converter.corpus().chars().collect();
+        chars.sort_unstable();
+        assert_eq!(chars.into_iter().collect::<String>(), base);
+
+        for number in [0, 1, 31, 32, 1234, u64::MAX as u128, u128::MAX] {
+            assert_eq!(converter.decode(&converter.convert(number)), Ok(number));
+        }
+        let converter = AsciiConverter::shuffled("αβγδε", 7).expect("valid corpus");
+        assert_eq!(converter.decode(&converter.convert(123456u128)), Ok(123456));
+
+        assert_eq!(AsciiConverter::shuffled("a", 1), Err(Error::TooShortCorpus));
+        assert_eq!(
+            AsciiConverter::shuffled("abca", 1),
+            Err(Error::DuplicateChar('a'))
+        );
+    }
+
+    #[test]
+    fn test_multibyte_corpus() {
+        let converter = AsciiConverter::from_corpus("αβγδε").expect("valid corpus");
+        assert_eq!(converter.convert(0u128), "α");
+        assert_eq!(converter.convert(4u128), "ε");
+        assert_eq!(converter.convert(5u128), "βα");
+        assert_eq!(converter.convert(123u128), "εεδ");
+        assert_eq!(converter.decode("εεδ"), Ok(123));
+        assert_eq!(
+            converter.decode("εxγ"),
+            Err(Error::InvalidChar { ch: 'x', pos: 2 })
+        );
+        for number in [0, 1, 24, 25, 3125, u64::MAX as u128, u128::MAX] {
+            assert_eq!(converter.decode(&converter.convert(number)), Ok(number));
+        }
+    }
+
+    #[test]
+    fn test_unambiguous() {
+        let converter = AsciiConverter::unambiguous();
+        assert_eq!(converter.chars.len(), 56);
+        for ch in ['0', 'O', 'o', '1', 'I', 'l'] {
+            assert!(!converter.chars.contains(&ch));
+        }
+        assert_eq!(converter.convert(0u128), "2");
+        assert_eq!(converter.convert(55u128), "z");
+        assert_eq!(converter.convert(56u128), "32");
+        for number in [0, 123456, u64::MAX as u128, u128::MAX] {
+            assert_eq!(converter.decode(&converter.convert(number)), Ok(number));
+        }
+    }
+
+    #[test]
+    fn test_base58() {
+        fn from_be_bytes(bytes: &[u8]) -> u128 {
+            let mut padded = [0; 16];
+            padded[16 - bytes.len()..].copy_from_slice(bytes);
+            u128::from_be_bytes(padded)
+        }
+
+        let converter = AsciiConverter::base58();
+        assert_eq!(converter.chars.len(), 58);
+        assert_eq!(converter.convert(0u128), "1");
+        assert_eq!(converter.convert(57u128), "z");
+        assert_eq!(converter.convert(58u128), "21");
+        assert_eq!(converter.convert(from_be_bytes(b"a")), "2g");
+        assert_eq!(converter.convert(from_be_bytes(b"abc")), "ZiCa");
+        assert_eq!(
+            converter.convert(from_be_bytes(b"Hello World!")),
+            "2NEpo7TZRRrLZSi2U"
+        );
+        assert_eq!(
+            converter.convert(from_be_bytes(&[0x28, 0x7f, 0xb4, 0xcd])),
+            "233QC4"
+        );
+        assert_eq!(
+            converter.convert(from_be_bytes(&[0xff; 16])),
+            "YcVfxkQb6JRzqk5kF2tNLv"
+        );
+        assert_eq!(
+            converter.decode("2NEpo7TZRRrLZSi2U"),
+            Ok(from_be_bytes(b"Hello World!"))
+        );
+        assert_eq!(
+            converter.decode("2NEpo7TZRRrLZSi2O"),
+            Err(Error::InvalidChar { ch: 'O', pos: 16 })
+        );
+    }
+
+    #[test]
+    fn test_crockford_base32() {
+        let converter = AsciiConverter::crockford_base32();
+        assert_eq!(converter.chars.len(), 32);
+        assert_eq!(converter.convert(0u128), "0");
+        assert_eq!(converter.convert(31u128), "Z");
+        assert_eq!(converter.convert(32u128), "10");
+        assert_eq!(converter.convert(1234u128), "16J");
+        assert_eq!(converter.encoded_len(1234u128), 3);
+        assert_eq!(converter.decode("16J"), Ok(1234));
+        assert_eq!(converter.decode("16j"), Ok(1234));
+        assert_eq!(converter.decode("I6J"), Ok(1234));
+        assert_eq!(converter.decode("l6J"), Ok(1234));
+        assert_eq!(converter.decode("O"), Ok(0));
+        assert_eq!(converter.decode("oo1"), Ok(1));
+        assert_eq!(
+            converter.decode("16U"),
+            Err(Error::InvalidChar { ch: 'U', pos: 2 })
+        );
+        for number in [0, 1, 1234, u64::MAX as u128, u128::MAX] {
+            let ascii = converter.convert(number);
+            assert_eq!(converter.decode(&ascii), Ok(number));
+            assert_eq!(converter.decode(&ascii.to_lowercase()), Ok(number));
+        }
+    }
+
+    #[test]
+    fn test_crockford_base32_with_check() {
+        let converter = AsciiConverter::crockford_base32_with_check();
+        assert_eq!(converter.convert(0u128), "00");
+        assert_eq!(converter.convert(31u128), "ZZ");
+        assert_eq!(converter.convert(32u128), "10*");
+        assert_eq!(converter.convert(36u128), "14U");
+        assert_eq!(converter.convert(37u128), "150");
+        assert_eq!(converter.convert(1234u128), "16JD");
+        assert_eq!(converter.encoded_len(1234u128), 4);
+        assert_eq!(converter.convert_padded(1234u128, 6), "0016JD");
+        assert_eq!(converter.decode("16JD"), Ok(1234));
+        assert_eq!(converter.decode("16jd"), Ok(1234));
+        assert_eq!(converter.decode("0016JD"), Ok(1234));
+        assert_eq!(converter.decode("14u"), Ok(36));
+        assert_eq!(converter.decode("150"), Ok(37));
+        assert_eq!(converter.decode("15O"), Ok(37));
+
+        // single substitutions and adjacent transpositions are caught
+        assert_eq!(converter.decode("16KD"), Err(Error::CheckMismatch));
+        assert_eq!(converter.decode("61JD"), Err(Error::CheckMismatch));
+        assert_eq!(converter.decode("16JE"), Err(Error::CheckMismatch));
+
+        assert_eq!(converter.decode(""), Err(Error::Empty));
+        assert_eq!(converter.decode("D"), Err(Error::Empty));
+        assert_eq!(
+            converter.decode("16J!"),
+            Err(Error::InvalidChar { ch: '!', pos: 3 })
+        );
+        assert_eq!(
+            converter.decode("1*JD"),
+            Err(Error::InvalidChar { ch: '*', pos: 1 })
+        );
+        for number in [0, 1, 1234, u64::MAX as u128, u128::MAX] {
+            let ascii = converter.convert(number);
+            assert_eq!(converter.decode(&ascii), Ok(number));
+            assert_eq!(converter.decode(&ascii.to_lowercase()), Ok(number));
+        }
+    }
+
+    #[test]
+    fn test_with_checksum() {
+        let converter = AsciiConverter::new(&RadixSettings::default()).with_checksum();
+        assert_eq!(converter.convert(0u128), "00");
+        assert_eq!(converter.convert(123456u128), "L7CH");
+        assert_eq!(converter.encoded_len(123456u128), 4);
+        assert_eq!(converter.convert_padded(123456u128, 6), "00L7CH");
+        assert_eq!(converter.decode("L7CH"), Ok(123456));
+        assert_eq!(converter.decode("00L7CH"), Ok(123456));
+        assert_eq!(converter.decode("L7CG"), Err(Error::CheckMismatch));
+        assert_eq!(converter.decode("H"), Err(Error::Empty));
+        assert_eq!(
+            converter.decode("L7C!"),
+            Err(Error::InvalidChar { ch: '!', pos: 3 })
+        );
+
+        // every single substitution and every adjacent transposition (except
+        // the zero digit with the last digit of even bases) is caught
+        for (corpus, number) in [
+            ("0123456789", 9_876_543_210_123u128),
+            ("abcdefghijklmnopqrstuvwxyz", u64::MAX as u128),
+            ("αβγδε", 123456789),
+        ] {
+            let converter = AsciiConverter::from_corpus(corpus)
+                .expect("valid corpus")
+                .with_checksum();
+            let chars: Vec<char> = converter.convert(number).chars().collect();
+            assert_eq!(
+                converter.decode(&chars.iter().collect::<String>()),
+                Ok(number)
+            );
+            for pos in 0..chars.len() {
+                for &ch in converter.chars.iter().filter(|&&ch| ch != chars[pos]) {
+                    let mut typo = chars.clone();
+                    typo[pos] = ch;
+                    let typo: String = typo.into_iter().collect();
+                    assert_eq!(
+                        converter.decode(&typo),
+                        Err(Error::CheckMismatch),
+                        "{}",
+                        typo
+                    );
+                }
+                if pos + 1 < chars.len() && chars[pos] != chars[pos + 1] {
+                    let pair = [chars[pos], chars[pos + 1]];
+                    if converter.chars.len().is_multiple_of(2)
+                        && pair.contains(&converter.chars[0])
+                        && pair.contains(&converter.chars[converter.chars.len() - 1])
+                    {
+                        continue;
+                    }
+                    let mut typo = chars.clone();
+                    typo.swap(pos, pos + 1);
+                    let typo: String = typo.into_iter().collect();
+                    assert_eq!(
+                        converter.decode(&typo),
+                        Err(Error::CheckMismatch),
+                        "{}",
+                        typo
+                    );
+                }
+            }
+        }
+
+        // roundtrip, and it replaces the crockford check symbol
+        let converter = AsciiConverter::crockford_base32_with_check().with_checksum();
+        assert_eq!(converter.encoded_len(1234u128), 4);
+        for number in [0, 1, 36, 37, 1234, u64::MAX as u128, u128::MAX] {
+            let ascii = converter.convert(number);
+            assert!(converter
+                .chars
+                .contains(&ascii.chars().last().expect("output isn't empty")));
+            assert_eq!(converter.decode(&ascii), Ok(number));
+            assert_eq!(converter.decode(&ascii.to_lowercase()), Ok(number));
+        }
+    }
+
+    #[test]
+    fn test_convert_fixed() {
+        let converter = AsciiConverter::from_corpus("0123456789").expect("valid corpus");
+        assert_eq!(converter.max_value_for_width(0), Err(Error::WidthTooShort));
+        assert_eq!(converter.max_value_for_width(1), Ok(Some(9)));
+        assert_eq!(converter.max_value_for_width(3), Ok(Some(999)));
+        assert_eq!(
+            converter.max_value_for_width(38),
+            Ok(Some(10u128.pow(38) - 1))
+        );
+        assert_eq!(converter.max_value_for_width(39), Ok(None));
+        assert_eq!(converter.max_value_for_width(usize::MAX), Ok(None));
+
+        assert_eq!(converter.convert_fixed(0u128, 3), Ok("000".into()));
+        assert_eq!(converter.convert_fixed(42u8, 3), Ok("042".into()));
+        assert_eq!(converter.convert_fixed(999u128, 3), Ok("999".into()));
+        assert_eq!(converter.convert_fixed(1000u128, 3), Err(Error::Overflow));
+        assert_eq!(converter.convert_fixed(0u128, 0), Err(Error::Overflow));
+        for width in 1..40 {
+            let max = converter
+                .max_value_for_width(width)
+                .expect("width holds digits")
+                .unwrap_or(u128::MAX);
+            let fixed = converter.convert_fixed(max, width).expect("max value fits");
+            assert_eq!(fixed.len(), width);
+            assert_eq!(converter.decode(&fixed), Ok(max));
+            if max < u128::MAX {
+                assert_eq!(
+                    converter.convert_fixed(max + 1, width),
+                    Err(Error::Overflow)
+                );
+            }
+        }
+
+        let converter = AsciiConverter::from_corpus("01").expect("valid corpus");
+        assert_eq!(converter.max_value_for_width(127), Ok(Some(u128::MAX >> 1)));
+        assert_eq!(converter.max_value_for_width(128), Ok(None));
+        assert_eq!(converter.convert_fixed(u128::MAX, 128), Ok("1".repeat(128)));
+
+        // check symbol takes one character of the width
+        let converter = AsciiConverter::crockford_base32_with_check();
+        assert_eq!(converter.max_value_for_width(0), Err(Error::WidthTooShort));
+        assert_eq!(converter.max_value_for_width(1), Err(Error::WidthTooShort));
+        assert_eq!(converter.max_value_for_width(2), Ok(Some(31)));
+        assert_eq!(converter.max_value_for_width(27), Ok(None));
+        assert_eq!(
+            Error::WidthTooShort.to_string(),
+            "width is too short to hold any number"
+        );
+        assert_eq!(converter.convert_fixed(1234u128, 6), Ok("0016JD".into()));
+        assert_eq!(converter.convert_fixed(1234u128, 3), Err(Error::Overflow));
+        assert_eq!(converter.convert_fixed(31u128, 2), Ok("ZZ".into()));
+        assert_eq!(converter.convert_fixed(32u128, 2), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_convert_grouped() {
+        let converter = AsciiConverter::new(&RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::All,
+            RadixLetters::Insensitive,
+        ));
+        let number = u64::MAX as u128;
+        assert_eq!(converter.convert(number), "3w5e11264sgsf");
+        assert_eq!(
+            converter.convert_grouped(number, 4, '-'),
+            Ok("3w5e-1126-4sgs-f".into())
+        );
+        assert_eq!(
+            converter.convert_grouped(36u128.pow(7), 4, ' '),
+            Ok("1000 0000".into())
+        );
+        assert_eq!(converter.convert_grouped(0u128, 4, '-'), Ok("0".into()));
+        assert_eq!(
+            converter.convert_grouped(number, 0, '-'),
+            Ok(converter.convert(number))
+        );
+        assert_eq!(
+            converter.convert_grouped(number, 100, '-'),
+            Ok(converter.convert(number))
+        );
+        assert_eq!(
+            converter.convert_grouped(number, 4, '·'),
+            Ok("3w5e·1126·4sgs·f".into())
+        );
+        assert_eq!(
+            converter.convert_grouped(number, 4, 'x'),
+            Err(Error::SeparatorInCorpus('x'))
+        );
+
+        assert_eq!(
+            converter.decode_grouped("3w5e-1126-4sgs-f", '-'),
+            Ok(number)
+        );
+        assert_eq!(
+            converter.decode_grouped("-3w5e--11264sgsf-", '-'),
+            Ok(number)
+        );
+        assert_eq!(converter.decode_grouped("3w5e11264sgsf", '-'), Ok(number));
+        assert_eq!(converter.decode_grouped("--", '-'), Err(Error::Empty));
+        assert_eq!(
+            converter.decode_grouped("3w·5e·!1", '·'),
+            Err(Error::InvalidChar { ch: '!', pos: 8 })
+        );
+        assert_eq!(
+            converter.decode_grouped("3w5e", '5'),
+            Err(Error::SeparatorInCorpus('5'))
+        );
+        for number in [0, 1, 35, 36, 123456, u128::MAX] {
+            for group in 0..6 {
+                let grouped = converter
+                    .convert_grouped(number, group, '-')
+                    .expect("valid separator");
+                assert_eq!(converter.decode_grouped(&grouped, '-'), Ok(number));
+            }
+        }
+
+        // aliases, other case and check symbols can't be separators either
+        let converter = AsciiConverter::crockford_base32_with_check();
+        assert_eq!(
+            converter.convert_grouped(1234u128, 2, '-'),
+            Ok("16-JD".into())
+        );
+        for sep in ['O', 'o', 'l', 'j', '*', 'u'] {
+            assert_eq!(
+                converter.convert_grouped(1234u128, 2, sep),
+                Err(Error::SeparatorInCorpus(sep))
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_into() {
+        let converter = AsciiConverter::new(&RadixSettings::new(
+            RadixSymbols::All,
+            RadixNumbers::All,
+            RadixLetters::Sensitive,
+        ));
+        let mut out = String::from("leftover");
+        converter.convert_into(u128::MAX, &mut out);
+        assert_eq!(out, r##",#7zGM_d_e&[bar**m,."##);
+        converter.convert_into(0u128, &mut out);
+        assert_eq!(out, "!");
+        for number in [1, 93, 94, 123456, u128::MAX - 1] {
+            converter.convert_into(number, &mut out);
+            assert_eq!(out, converter.convert(number));
+        }
+    }
+
+    #[test]
+    fn test_digit_order() {
+        let numbers = [0, 1, 61, 62, 123456, u128::MAX - 1, u128::MAX];
+        for converter in [
+            AsciiConverter::new(&RadixSettings::default()),
+            AsciiConverter::from_corpus("01").expect("valid corpus"),
+            AsciiConverter::from_corpus("αβγδε").expect("valid corpus"),
+        ] {
+            let reversed = converter
+                .clone()
+                .with_digit_order(DigitOrder::LeastSignificantFirst);
+            for number in numbers {
+                let msb_first = converter.convert(number);
+                let lsb_first = reversed.convert(number);
+                assert_eq!(lsb_first, msb_first.chars().rev().collect::<String>());
+                assert_eq!(converter.decode(&msb_first), Ok(number));
+                assert_eq!(reversed.decode(&lsb_first), Ok(number));
+                assert_eq!(reversed.encoded_len(number), lsb_first.chars().count());
+            }
+        }
+
+        let converter = AsciiConverter::new(&RadixSettings::default())
+            .with_digit_order(DigitOrder::LeastSignificantFirst);
+        assert_eq!(converter.convert(62u128), "01");
+        // padding zeros are the most significant digits, so they go last
+        assert_eq!(converter.convert_padded(62u128, 4), "0100");
+        assert_eq!(converter.decode("0100"), Ok(62));
+        assert_eq!(
+            converter.decode("zzzzzzzzzzzzzzzzzzzzzzzz"),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            converter.decode("0!"),
+            Err(Error::InvalidChar { ch: '!', pos: 1 })
+        );
+
+        // check symbol stays at the end
+        let converter = AsciiConverter::crockford_base32_with_check();
+        let reversed = converter
+            .clone()
+            .with_digit_order(DigitOrder::LeastSignificantFirst);
+        assert_eq!(converter.convert(1234u128), "16JD");
+        assert_eq!(reversed.convert(1234u128), "J61D");
+        assert_eq!(reversed.convert_padded(1234u128, 6), "J6100D");
+        assert_eq!(reversed.decode("J61D"), Ok(1234));
+        assert_eq!(reversed.decode("J6100D"), Ok(1234));
+        assert_eq!(reversed.decode("16JD"), Err(Error::CheckMismatch));
+        let converter = AsciiConverter::new(&RadixSettings::default())
+            .with_checksum()
+            .with_digit_order(DigitOrder::LeastSignificantFirst);
+        assert_eq!(converter.convert(123456u128), "C7LH");
+        assert_eq!(converter.decode("C7LH"), Ok(123456));
+    }
+
+    #[test]
+    fn test_base() {
+        for settings in [
+            RadixSettings::default(),
+            "aai".parse().expect("valid RADIXOPT"),
+            "uds".parse().expect("valid RADIXOPT"),
+            "ddi".parse().expect("valid RADIXOPT"),
+            RadixSettings::builder()
+                .letters(RadixLetters::Custom("çğıöşü".into()))
+                .build(),
+        ] {
+            let converter = AsciiConverter::new(&settings);
+            assert_eq!(converter.base(), settings.corpus().chars().count());
+            assert_eq!(converter.base(), converter.corpus().chars().count());
+        }
+        assert_eq!(AsciiConverter::base58().base(), 58);
+        // base is computed once, while building the converter
+        for converter in [
+            AsciiConverter::unambiguous(),
+            AsciiConverter::shuffled("0123456789", 7).expect("valid corpus"),
+            AsciiConverter::from_corpus("01").expect("valid corpus"),
+        ] {
+            assert_eq!(converter.base.get(), converter.chars.len());
+        }
+        let converter = AsciiConverter::crockford_base32();
+        assert_eq!(
+            converter.max_value_for_width(3),
+            Ok(Some((converter.base() as u128).pow(3) - 1))
+        );
+    }
+
+    #[test]
+    fn test_char_for_digit() {
+        for converter in [
+            AsciiConverter::new(&RadixSettings::default()),
+            AsciiConverter::new(&"aai".parse().expect("valid RADIXOPT")),
+            AsciiConverter::from_corpus("αβγδε").expect("valid corpus"),
+            AsciiConverter::crockford_base32_with_check(),
+        ] {
+            for (digit, ch) in converter.corpus().chars().enumerate() {
+                assert_eq!(converter.char_for_digit(digit), Some(ch));
+                assert_eq!(converter.digit_for_char(ch), Some(digit));
+            }
+            assert_eq!(converter.char_for_digit(converter.base()), None);
+            assert_eq!(converter.char_for_digit(usize::MAX), None);
+            assert_eq!(converter.digit_for_char('\0'), None);
+            assert_eq!(converter.digit_for_char('🦀'), None);
+        }
+
+        // these are the building blocks of convert and decode
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let token: String = BaseConvertIter::new(123456u128, NonZeroUsize::new(62).unwrap())
+            .rev()
+            .map(|digit| converter.char_for_digit(digit).unwrap())
+            .collect();
+        assert_eq!(token, converter.convert(123456u128));
+        let number = token.chars().fold(0, |number, ch| {
+            number * 62 + converter.digit_for_char(ch).unwrap() as u128
+        });
+        assert_eq!(converter.decode(&token), Ok(number));
+
+        // alternative characters are read as digits too
+        let converter = AsciiConverter::new(&"dai".parse().expect("valid RADIXOPT"));
+        assert_eq!(converter.digit_for_char('A'), Some(10));
+        assert_eq!(converter.digit_for_char('a'), Some(10));
+        assert_eq!(converter.char_for_digit(10), Some('a'));
+        let converter = AsciiConverter::crockford_base32_with_check();
+        assert_eq!(converter.digit_for_char('L'), Some(1));
+        assert_eq!(converter.digit_for_char('*'), None);
+    }
+
+    #[test]
+    fn test_convert_inline() {
+        let numbers = [0, 1, 61, 123456, u64::MAX as u128, u128::MAX];
+        for converter in [
+            AsciiConverter::new(&RadixSettings::default()),
+            AsciiConverter::new(&RadixSettings::default())
+                .with_checksum()
+                .with_digit_order(DigitOrder::LeastSignificantFirst),
+            AsciiConverter::crockford_base32_with_check(),
+            AsciiConverter::from_corpus("0123").expect("valid corpus"),
+        ] {
+            for number in numbers {
+                let token = converter.convert_inline(number);
+                assert_eq!(token, *converter.convert(number));
+                assert!(token.is_inline(), "{:?} {}", converter, number);
+            }
+        }
+
+        // outputs longer than the buffer are allocated
+        let converter = AsciiConverter::from_corpus("01").expect("valid corpus");
+        let token = converter.convert_inline(u128::from(u64::MAX));
+        assert_eq!(token.len(), 64);
+        assert!(token.is_inline());
+        let token = converter.convert_inline(u128::from(u64::MAX) + 1);
+        assert_eq!(token.as_str(), converter.convert(u128::from(u64::MAX) + 1));
+        assert!(!token.is_inline());
+        assert_eq!(String::from(token), "1".to_owned() + &"0".repeat(64));
+
+        // check symbol and padding count too
+        let converter = AsciiConverter::from_corpus("0123")
+            .expect("valid corpus")
+            .with_checksum();
+        let token = converter.convert_inline(u128::MAX);
+        assert_eq!(token.len(), 65);
+        assert_eq!(token, *converter.convert(u128::MAX));
+        assert!(!token.is_inline());
+        let converter = AsciiConverter::new(&RadixSettings::default()).with_min_width(65);
+        let token = converter.convert_inline(61u8);
+        assert_eq!(token.as_str(), "0".repeat(64) + "z");
+        assert_eq!(token, *converter.convert(61u8));
+        assert!(!token.is_inline());
+
+        // multibyte characters take more space
+        let converter = AsciiConverter::from_corpus("αβγδε").expect("valid corpus");
+        let token = converter.convert_inline(5u128.pow(31));
+        assert_eq!(token.len(), 64);
+        assert!(token.is_inline());
+        let token = converter.convert_inline(5u128.pow(32));
+        assert_eq!(token, *converter.convert(5u128.pow(32)));
+        assert!(!token.is_inline());
+
+        let token = AsciiConverter::crockford_base32().convert_inline(1234u32);
+        assert_eq!(token.to_string(), "16J");
+        assert_eq!(format!("{:?}", token), "\"16J\"");
+        assert_eq!(token, token.clone());
+    }
+
+    #[test]
+    fn test_display() {
+        let numbers = [0, 1, 61, 123456, u64::MAX as u128, u128::MAX];
+        for converter in [
+            AsciiConverter::new(&RadixSettings::default()),
+            AsciiConverter::from_corpus("αβγδε").expect("valid corpus"),
+            AsciiConverter::crockford_base32_with_check()
+                .with_digit_order(DigitOrder::LeastSignificantFirst)
+                .with_min_width(10),
+        ] {
+            for number in numbers {
+                assert_eq!(
+                    converter.display(number).to_string(),
+                    converter.convert(number)
+                );
+            }
+        }
+        let converter = AsciiConverter::crockford_base32();
+        assert_eq!(
+            format!(
+                "[{}] [{}]",
+                converter.display(31u8),
+                converter.display(32u64)
+            ),
+            "[Z] [10]"
+        );
+    }
+
+    #[test]
+    fn test_convert_signed() {
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        for (number, token) in [
+            (0, "0"),
+            (-1, "-1"),
+            (1, "1"),
+            (-123456, "-L7C"),
+            (i128::MAX, "3wl16oa2ruWW4wmHiMOVU3"),
+            (i128::MIN, "-3wl16oa2ruWW4wmHiMOVU4"),
+        ] {
+            assert_eq!(converter.convert_signed(number), Ok(token.into()));
+            assert_eq!(converter.decode_signed(token), Ok(number));
+        }
+        assert_eq!(
+            converter.convert_signed(i128::MIN),
+            Ok(format!("-{}", converter.convert(1u128 << 127)))
         );
+        // negative zero is still zero
+        assert_eq!(converter.decode_signed("-0"), Ok(0));
         assert_eq!(
-            BaseConvertIter::new(5, NonZeroUsize::new(1000).expect("not zero"))
-                .collect::<Vec<usize>>(),
-            vec![5]
+            converter.decode_signed("-3wl16oa2ruWW4wmHiMOVU5"),
+            Err(Error::Overflow)
         );
         assert_eq!(
-            BaseConvertIter::new(123456, NonZeroUsize::new(62).expect("not zero"))
-                .collect::<Vec<usize>>(),
-            vec![14, 7, 32]
+            converter.decode_signed("3wl16oa2ruWW4wmHiMOVU4"),
+            Err(Error::Overflow)
         );
+        assert_eq!(converter.decode_signed("-"), Err(Error::Empty));
+        assert_eq!(converter.decode_signed(""), Err(Error::Empty));
         assert_eq!(
-            BaseConvertIter::new(837, NonZeroUsize::new(2).expect("not zero"))
-                .collect::<Vec<usize>>(),
-            vec![1, 0, 1, 0, 0, 0, 1, 0, 1, 1]
+            converter.decode_signed("--1"),
+            Err(Error::InvalidChar { ch: '-', pos: 1 })
         );
+
+        let converter = converter.with_sign('~').with_checksum();
+        assert_eq!(converter.convert_signed(-123456), Ok("~L7CH".into()));
+        assert_eq!(converter.decode_signed("~L7CH"), Ok(-123456));
         assert_eq!(
-            BaseConvertIter::new(u128::MAX, NonZeroUsize::new(19209).expect("not zero"))
-                .collect::<Vec<usize>>(),
-            vec![3, 8970, 6739, 1611, 15517, 3461, 285, 18953, 18356]
+            converter.decode_signed("-L7CH"),
+            Err(Error::InvalidChar { ch: '-', pos: 0 })
+        );
+        assert_eq!(
+            converter.decode_signed("~L7C!"),
+            Err(Error::InvalidChar { ch: '!', pos: 4 })
+        );
+
+        // signs that could be read as digits are refused
+        let converter = AsciiConverter::new(&"uai".parse().expect("valid RADIXOPT"));
+        assert_eq!(converter.convert_signed(1), Err(Error::SignInCorpus('-')));
+        assert_eq!(converter.decode_signed("1"), Err(Error::SignInCorpus('-')));
+        let converter = AsciiConverter::new(&"dai".parse().expect("valid RADIXOPT")).with_sign('N');
+        assert_eq!(converter.convert_signed(-1), Err(Error::SignInCorpus('N')));
+        let converter = AsciiConverter::crockford_base32_with_check().with_sign('*');
+        assert_eq!(converter.convert_signed(-1), Err(Error::SignInCorpus('*')));
+        assert_eq!(
+            Error::SignInCorpus('*').to_string(),
+            "sign `*` can't be a member of corpus"
         );
     }
 
     #[test]
-    fn test_convert_to_ascii() {
+    fn test_convert_bytes_out() {
+        let numbers = [0, 1, 61, 123456, u64::MAX as u128, u128::MAX];
+        for converter in [
+            AsciiConverter::new(&RadixSettings::default()),
+            AsciiConverter::new(&"aai".parse().expect("valid RADIXOPT")),
+            AsciiConverter::new(&"uds".parse().expect("valid RADIXOPT")).with_min_width(30),
+            AsciiConverter::unambiguous(),
+            AsciiConverter::base58(),
+            AsciiConverter::crockford_base32_with_check(),
+            AsciiConverter::new(&RadixSettings::default()).with_checksum(),
+        ] {
+            for number in numbers {
+                assert_eq!(
+                    converter.convert_bytes_out(number).as_deref(),
+                    Ok(converter.convert(number).as_bytes())
+                );
+            }
+        }
+        let converter = AsciiConverter::from_corpus("01é").expect("valid corpus");
+        assert_eq!(converter.convert_bytes_out(0u8), Err(Error::NonAsciiCorpus));
         assert_eq!(
+            Error::NonAsciiCorpus.to_string(),
+            "corpus must only have ASCII characters"
+        );
+    }
+
+    #[test]
+    fn test_convert_all() {
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        assert_eq!(converter.convert_all(&[0u128, 1, 61], ","), "0,1,z");
+        assert_eq!(converter.convert_all(&[123456u128], ","), "L7C");
+        assert_eq!(converter.convert_all::<u128>(&[], ","), "");
+        assert_eq!(converter.convert_all(&[1u8, 2, 3], ""), "123");
+
+        let numbers = [0, 123456, u128::MAX];
+        let converter = AsciiConverter::crockford_base32_with_check();
+        let tokens: Vec<String> = converter.convert_iter(numbers).collect();
+        assert_eq!(converter.convert_all(&numbers, " - "), tokens.join(" - "));
+    }
+
+    #[test]
+    fn test_encoded_len() {
+        let mut numbers = vec![0, 1, u128::MAX - 1, u128::MAX];
+        for exp in 1..128 {
+            let power = 1u128 << exp;
+            numbers.extend([power - 1, power, power + 1]);
+        }
+        for base in [2u128, 10, 26, 36, 62, 94] {
+            let mut power = base;
+            while let Some(next) = power.checked_mul(base) {
+                numbers.extend([power - 1, power, power + 1]);
+                power = next;
+            }
+        }
+        for converter in [
+            AsciiConverter::from_corpus("01").expect("valid corpus"),
+            AsciiConverter::from_corpus("αβγδε").expect("valid corpus"),
             AsciiConverter::new(&RadixSettings::new(
                 RadixSymbols::Disabled,
                 RadixNumbers::Disabled,
                 RadixLetters::Insensitive,
-            ))
-            .convert(0),
-            "a"
-        );
-        assert_eq!(
+            )),
             AsciiConverter::new(&RadixSettings::new(
                 RadixSymbols::Disabled,
                 RadixNumbers::All,
-                RadixLetters::Insensitive,
-            ))
-            .convert(0),
-            "0"
-        );
-        assert_eq!(
+                RadixLetters::SensitiveOrdered,
+            )),
             AsciiConverter::new(&RadixSettings::new(
                 RadixSymbols::All,
                 RadixNumbers::All,
-                RadixLetters::Insensitive,
-            ))
-            .convert(0),
-            "!"
-        );
+                RadixLetters::Sensitive,
+            )),
+        ] {
+            for &number in &numbers {
+                assert_eq!(
+                    converter.encoded_len(number),
+                    converter.convert(number).chars().count(),
+                    "{:?} {}",
+                    converter,
+                    number
+                );
+            }
+        }
+    }
 
+    #[test]
+    fn test_convert_iter() {
+        let converter = AsciiConverter::from_corpus("01").expect("valid corpus");
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::All,
-                RadixNumbers::All,
-                RadixLetters::Insensitive,
-            ))
-            .convert(u128::MAX),
-            r##"")+'4/yf`dygv?{w*kdvnj"##
+            converter.convert_iter(0u128..5).collect::<Vec<String>>(),
+            vec!["0", "1", "10", "11", "100"]
         );
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::UnixSafe,
-                RadixNumbers::All,
-                RadixLetters::Insensitive,
-            ))
-            .convert(u128::MAX),
-            r##""4{173{}g^z'ikw8_<,~g@"##
+            converter
+                .convert_iter(0u8..5)
+                .rev()
+                .collect::<Vec<String>>(),
+            vec!["100", "11", "10", "1", "0"]
         );
+        assert_eq!(converter.convert_iter([7u32; 3]).len(), 3);
+        assert_eq!(converter.convert_iter(Vec::<u64>::new()).next(), None);
+
+        let numbers = [0, 1, 123456, u128::MAX];
+        let converter = AsciiConverter::crockford_base32_with_check();
+        assert!(converter
+            .convert_iter(numbers)
+            .eq(numbers.iter().map(|&number| converter.convert(number))));
+    }
+
+    #[test]
+    fn test_convert_padded() {
+        let converter = AsciiConverter::new(&RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::Disabled,
+            RadixLetters::SensitiveOrdered,
+        ));
+        assert_eq!(converter.convert_padded(0u128, 0), "A");
+        assert_eq!(converter.convert_padded(0u128, 1), "A");
+        assert_eq!(converter.convert_padded(0u128, 5), "AAAAA");
+        assert_eq!(converter.convert_padded(123u128, 5), "AAABj");
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::Disabled,
-                RadixNumbers::All,
-                RadixLetters::Insensitive,
-            ))
-            .convert(u128::MAX),
-            r##"f5lxx1zz5pnorynqglhzmsp33"##
+            converter.convert_padded(u128::MAX, 5),
+            converter.convert(u128::MAX)
         );
+
+        // padding must be the zero digit, so that padded output still decodes
+        // to the same number
+        assert_eq!(converter.decode(&converter.convert_padded(0u128, 5)), Ok(0));
+        for number in [1, 123, 123456, u128::MAX] {
+            assert_eq!(
+                converter.decode(&converter.convert_padded(number, 30)),
+                Ok(number)
+            );
+        }
+
+        let converter = AsciiConverter::from_corpus("αβγδε").expect("valid corpus");
+        assert_eq!(converter.convert_padded(5u128, 4), "ααβα");
+    }
+
+    #[test]
+    fn test_with_min_width() {
+        let numbers = [0, 1, 61, 123456, u64::MAX as u128, u128::MAX];
+        for converter in [
+            AsciiConverter::new(&RadixSettings::default()),
+            AsciiConverter::crockford_base32_with_check(),
+            AsciiConverter::new(&RadixSettings::default())
+                .with_checksum()
+                .with_digit_order(DigitOrder::LeastSignificantFirst),
+        ] {
+            let padded = converter.clone().with_min_width(8);
+            for number in numbers {
+                let token = padded.convert(number);
+                assert_eq!(token, converter.convert_padded(number, 8));
+                assert_eq!(padded.decode(&token), Ok(number));
+                assert_eq!(padded.encoded_len(number), token.chars().count());
+                assert_eq!(padded.convert_inline(number), *token);
+            }
+            assert_eq!(converter.clone().with_min_width(0), converter);
+        }
+
+        let converter = AsciiConverter::new(&RadixSettings::default()).with_min_width(5);
+        assert_eq!(converter.convert(123456u128), "00L7C");
+        assert_eq!(converter.convert_all(&[0u8, 61], ","), "00000,0000z");
+        assert_eq!(converter.convert_iter([61u8]).next().unwrap(), "0000z");
+        // wider one of the two widths wins
+        assert_eq!(converter.convert_padded(61u128, 3), "0000z");
+        assert_eq!(converter.convert_padded(61u128, 7), "000000z");
+        assert_eq!(converter.convert_padded_with(61u128, 3, ' '), "    z");
+        assert_eq!(converter.convert_fixed(61u128, 5), Ok("0000z".into()));
+        assert_eq!(converter.convert_fixed(61u128, 4), Err(Error::Overflow));
+        // narrower than the minimum width, nothing fits
+        assert_eq!(converter.max_value_for_width(4), Err(Error::WidthTooShort));
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::All,
-                RadixNumbers::Disabled,
-                RadixLetters::Insensitive,
-            ))
-            .convert(u128::MAX),
-            r##"~d{gxl\c&<]zql%l/"t@>v"##
+            converter.max_value_for_width(5),
+            Ok(Some(62u128.pow(5) - 1))
         );
+        assert_eq!(converter.max_value_for_width(22), Ok(None));
+    }
+
+    #[test]
+    fn test_zero_as_empty() {
+        let plain = AsciiConverter::new(&RadixSettings::default());
+        assert_eq!(plain.convert(0u128), "0");
+        assert_eq!(plain.decode(""), Err(Error::Empty));
+
+        let converter = plain.clone().with_zero_as_empty();
+        assert_ne!(converter, plain);
+        assert_eq!(converter.convert(0u8), "");
+        assert_eq!(converter.convert_inline(0u128), "");
+        assert_eq!(converter.display(0u128).to_string(), "");
+        assert_eq!(converter.encoded_len(0u128), 0);
+        assert_eq!(converter.decode(""), Ok(0));
+        // zero digits are still read as zero
+        assert_eq!(converter.decode("00"), Ok(0));
+        for number in 1..1000u128 {
+            assert_eq!(converter.convert(number), plain.convert(number));
+            assert_eq!(converter.decode(&converter.convert(number)), Ok(number));
+        }
+        assert_eq!(converter.max_value_for_width(0), Ok(Some(0)));
+        assert_eq!(converter.max_value_for_width(1), Ok(Some(61)));
+        assert_eq!(converter.convert_signed(0), Ok(String::new()));
+        assert_eq!(converter.decode_signed(""), Ok(0));
+        assert_eq!(converter.cmp_tokens("", "1"), Ordering::Less);
+        assert_eq!(converter.cmp_tokens("", "0"), Ordering::Less);
+
+        let padded = converter.clone().with_min_width(3);
+        assert_eq!(padded.convert(0u128), "000");
+        assert_eq!(padded.convert(1u128), "001");
+        let reversed = converter
+            .clone()
+            .with_digit_order(DigitOrder::LeastSignificantFirst);
+        assert_eq!(reversed.convert(0u128), "");
+        assert_eq!(reversed.decode(""), Ok(0));
+
+        // zero is only its check symbol
+        let checked = converter.with_checksum();
+        let token = checked.convert(0u128);
+        assert_eq!(token.chars().count(), 1);
+        assert_eq!(checked.encoded_len(0u128), 1);
+        assert_eq!(checked.decode(&token), Ok(0));
+        assert_eq!(checked.decode(""), Err(Error::Empty));
+        assert_eq!(checked.max_value_for_width(0), Err(Error::WidthTooShort));
+        assert_eq!(checked.max_value_for_width(1), Ok(Some(0)));
+    }
+
+    #[test]
+    fn test_convert_padded_with() {
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        // zero digit is the same as convert_padded
+        for number in [0, 61, 123456, u128::MAX] {
+            assert_eq!(
+                converter.convert_padded_with(number, 8, '0'),
+                converter.convert_padded(number, 8)
+            );
+        }
+        assert_eq!(converter.convert_padded_with(123456u128, 6, ' '), "   L7C");
+        assert_eq!(converter.convert_padded_with(123456u128, 2, ' '), "L7C");
+        assert_eq!(converter.convert_padded_with(0u128, 3, '·'), "··0");
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::UnixSafe,
-                RadixNumbers::Disabled,
-                RadixLetters::Insensitive,
-            ))
-            .convert(u128::MAX),
-            r##"oa-q&ov]c>q%`n:?[wd'*$"##
+            converter.decode("   L7C"),
+            Err(Error::InvalidChar { ch: ' ', pos: 0 })
         );
+        // a digit as padding decodes to another number
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::Disabled,
-                RadixNumbers::Disabled,
-                RadixLetters::Insensitive,
-            ))
-            .convert(u128::MAX),
-            r##"cdhefomrsrxetmsvhtomcungjkbv"##
+            converter.decode(&converter.convert_padded_with(61u128, 2, '1')),
+            Ok(123)
         );
+
+        // padding is still on the most significant side, before the check symbol
+        let converter = AsciiConverter::crockford_base32_with_check()
+            .with_digit_order(DigitOrder::LeastSignificantFirst);
+        assert_eq!(converter.convert_padded_with(1234u128, 6, '*'), "J61**D");
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_short_id() {
+        // reference values of FNV-1a
+        assert_eq!(fnv1a_128(b""), 0x6c62272e07bb014262b821756295c58d);
+        assert_eq!(fnv1a_128(b"a"), 0xd228cb696f1a8caf78912b704e4a8964);
+
+        let converter = AsciiConverter::crockford_base32();
+        assert_eq!(converter.short_id(b""), "3CC8KJW1XV05165E11ENH9BHCD");
+        assert_eq!(converter.short_id(b"a"), "6J535PJVRTHJQQH49BE174N2B4");
+        assert_eq!(converter.short_id(b"a"), converter.short_id(b"a"));
+        assert_ne!(converter.short_id(b"a"), converter.short_id(b"b"));
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::All,
-                RadixNumbers::All,
-                RadixLetters::Sensitive,
-            ))
-            .convert(u128::MAX),
-            r##",#7zGM_d_e&[bar**m,."##
+            converter.decode(&converter.short_id(b"hello")),
+            Ok(fnv1a_128(b"hello"))
         );
+    }
+
+    #[test]
+    fn test_cmp_tokens() {
+        // tokens are sorted by code point, which disagrees with their values
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let tokens = ["z", "a", "Z", "B", "A", "9", "0"];
+        assert!(tokens.is_sorted_by(|a, b| a > b));
+        let mut sorted = tokens;
+        sorted.sort_by(|a, b| converter.cmp_tokens(a, b));
+        assert_eq!(sorted, ["0", "9", "A", "a", "B", "Z", "z"]);
+        assert!(sorted
+            .iter()
+            .map(|token| converter.decode(token).unwrap())
+            .is_sorted());
+
+        assert_eq!(converter.cmp_tokens("z", "10"), Ordering::Less);
+        assert_eq!(converter.cmp_tokens("L7C", "L7C"), Ordering::Equal);
+        // leading zeros don't change the value, but equal values are still
+        // ordered by their characters
+        assert_eq!(converter.cmp_tokens("0z", "10"), Ordering::Less);
+        assert_eq!(converter.cmp_tokens("00L7C", "L7C"), Ordering::Less);
+        assert_eq!(converter.cmp_tokens("L7C", "00L7C"), Ordering::Greater);
+        // longer than u128
+        let huge = "z".repeat(30);
+        assert_eq!(converter.decode(&huge), Err(Error::Overflow));
+        assert_eq!(converter.cmp_tokens(&huge, "1"), Ordering::Greater);
+        // invalid tokens come last
+        assert_eq!(converter.cmp_tokens("!", &huge), Ordering::Greater);
+        assert_eq!(converter.cmp_tokens("", "0"), Ordering::Greater);
+        assert_eq!(converter.cmp_tokens("!", "?"), Ordering::Less);
+
+        let converter = AsciiConverter::new(&RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::Disabled,
+            RadixLetters::Insensitive,
+        ));
+        assert_eq!(converter.cmp_tokens("ET", "eu"), Ordering::Less);
+        assert_eq!(converter.cmp_tokens("ET", "et"), Ordering::Less);
+
+        // digit order and check symbols are taken into account
+        let converter = AsciiConverter::crockford_base32_with_check()
+            .with_digit_order(DigitOrder::LeastSignificantFirst);
+        let numbers = [0u128, 31, 32, 1234, 1235, 99999];
+        let tokens: Vec<String> = converter.convert_iter(numbers).collect();
+        for (i, a) in tokens.iter().enumerate() {
+            for (j, b) in tokens.iter().enumerate() {
+                assert_eq!(converter.cmp_tokens(a, b), i.cmp(&j), "{} {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode() {
+        let converter = AsciiConverter::new(&RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::Disabled,
+            RadixLetters::Insensitive,
+        ));
+        assert_eq!(converter.decode("a"), Ok(0));
+        assert_eq!(converter.decode("et"), Ok(123));
+        assert_eq!(converter.decode("aaet"), Ok(123));
+        assert_eq!(converter.decode(""), Err(Error::Empty));
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::UnixSafe,
-                RadixNumbers::All,
-                RadixLetters::Sensitive,
-            ))
-            .convert(u128::MAX),
-            r##".GB;(nA-8hN0iu?pLo4c"##
+            converter.decode("e7t"),
+            Err(Error::InvalidChar { ch: '7', pos: 1 })
         );
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::Disabled,
-                RadixNumbers::All,
-                RadixLetters::Sensitive,
-            ))
-            .convert(u128::MAX),
-            r##"7n42DGM5Tflk9n8mt7Fhc7"##
+            converter.decode("cdhefomrsrxetmsvhtomcungjkbv"),
+            Ok(u128::MAX)
         );
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::All,
-                RadixNumbers::Disabled,
-                RadixLetters::Sensitive,
-            ))
-            .convert(u128::MAX),
-            r##""*EwscH.T$Oa?x^]GS@f$"##
+            converter.decode("cdhefomrsrxetmsvhtomcungjkbw"),
+            Err(Error::Overflow)
         );
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::UnixSafe,
-                RadixNumbers::Disabled,
-                RadixLetters::Sensitive,
-            ))
-            .convert(u128::MAX),
-            r##""D`<#<C\nsD>`T%lg._?T"##
+            converter.decode("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzz"),
+            Err(Error::Overflow)
         );
+    }
+
+    #[test]
+    fn test_digit_table() {
+        let table = DigitTable::new(&['x', 'λ', '0', '🦀']);
+        assert_eq!(table.get('x'), Some(0));
+        assert_eq!(table.get('λ'), Some(1));
+        assert_eq!(table.get('0'), Some(2));
+        assert_eq!(table.get('🦀'), Some(3));
+        assert_eq!(table.get('X'), None);
+        assert_eq!(table.get('\x7f'), None);
+        assert_eq!(table.get('\u{80}'), None);
+
+        // aliases and case folding never override corpus characters
+        let mut table = DigitTable::new(&['a', 'B', 'A']);
+        table.add_alias('λ', 'B');
+        table.add_alias('a', 'B');
+        table.add_alias('c', 'z');
+        table.ignore_case();
+        assert_eq!(table.get('a'), Some(0));
+        assert_eq!(table.get('A'), Some(2));
+        assert_eq!(table.get('b'), Some(1));
+        assert_eq!(table.get('λ'), Some(1));
+        assert_eq!(table.get('c'), None);
+        assert_eq!(table.get('C'), None);
+    }
+
+    #[test]
+    fn test_decode_insensitive() {
+        let converter = AsciiConverter::new(&"ddi".parse().expect("valid radix"));
+        assert_eq!(converter.decode("et"), Ok(123));
+        assert_eq!(converter.decode("ET"), Ok(123));
+        assert_eq!(converter.decode("eT"), Ok(123));
+        assert_eq!(converter.convert(123u128), "et");
+
+        let converter = AsciiConverter::new(&"uai".parse().expect("valid radix"));
+        assert_eq!(converter.decode("A0!"), converter.decode("a0!"));
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::Disabled,
-                RadixNumbers::Disabled,
-                RadixLetters::Sensitive,
-            ))
-            .convert(u128::MAX),
-            r##"GBIWTpZqojFGQPQPXtvbJAv"##
+            converter.decode("a/b"),
+            Err(Error::InvalidChar { ch: '/', pos: 1 })
         );
+
+        // sensitive modes have both cases in the corpus, with different values
+        for radix in ["dds", "ddo"] {
+            let converter = AsciiConverter::new(&radix.parse().expect("valid radix"));
+            assert_ne!(converter.decode("ET"), converter.decode("et"));
+        }
+        // as well as custom letters, which are taken as they are
+        let converter = AsciiConverter::new(&RadixSettings::new(
+            RadixSymbols::Disabled,
+            RadixNumbers::All,
+            RadixLetters::Custom("aeiou".into()),
+        ));
         assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
+            converter.decode("E"),
+            Err(Error::InvalidChar { ch: 'E', pos: 0 })
+        );
+    }
+
+    #[test]
+    fn test_decode_roundtrip() {
+        let numbers = [
+            0,
+            1,
+            9,
+            10,
+            61,
+            62,
+            123456,
+            u64::MAX as u128,
+            u64::MAX as u128 + 1,
+            u128::MAX / 3,
+            u128::MAX - 1,
+            u128::MAX,
+        ];
+        for settings in [
+            RadixSettings::new(
                 RadixSymbols::All,
                 RadixNumbers::All,
-                RadixLetters::SensitiveOrdered,
-            ))
-            .convert(u128::MAX),
-            r##",#7zDG_o_P&[nNv**T,."##
-        );
-        assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
+                RadixLetters::Sensitive,
+            ),
+            RadixSettings::new(
                 RadixSymbols::UnixSafe,
-                RadixNumbers::All,
-                RadixLetters::SensitiveOrdered,
-            ))
-            .convert(u128::MAX),
-            r##".Da;(tA-8qg0RX?ufU4O"##
-        );
-        assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
+                RadixNumbers::Disabled,
+                RadixLetters::Insensitive,
+            ),
+            RadixSettings::new(
                 RadixSymbols::Disabled,
                 RadixNumbers::All,
                 RadixLetters::SensitiveOrdered,
-            ))
-            .convert(u128::MAX),
-            r##"7t42bDG5jpsS9t8Tw7cqO7"##
-        );
-        assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::All,
-                RadixNumbers::Disabled,
-                RadixLetters::SensitiveOrdered,
-            ))
-            .convert(u128::MAX),
-            r##""*CYWOd.j$HN?y^]DJ@p$"##
-        );
-        assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
-                RadixSymbols::UnixSafe,
-                RadixNumbers::Disabled,
-                RadixLetters::SensitiveOrdered,
-            ))
-            .convert(u128::MAX),
-            r##""b`<#<B\tWb>`j%sQ._?j"##
-        );
-        assert_eq!(
-            AsciiConverter::new(&RadixSettings::new(
+            ),
+            RadixSettings::new(
                 RadixSymbols::Disabled,
                 RadixNumbers::Disabled,
-                RadixLetters::SensitiveOrdered,
-            ))
-            .convert(u128::MAX),
-            r##"DaELjumVUrcDIhIhlwxneAx"##
-        );
+                RadixLetters::Insensitive,
+            ),
+        ] {
+            let converter = AsciiConverter::new(&settings);
+            for number in numbers {
+                assert_eq!(
+                    converter.decode(&converter.convert(number)),
+                    Ok(number),
+                    "{:?} {}",
+                    settings,
+                    number
+                );
+            }
+        }
     }
 
     #[test]
@@ -418,4 +4275,63 @@ mod tests {
         assert_eq!(Vec::from(b"asd").trim_ascii_control(), b"asd");
         assert_eq!(Vec::from(b"").trim_ascii_control(), b"");
     }
+
+    #[test]
+    fn test_trim_ascii_control_str() {
+        assert_eq!("\t\n\rX\x00\x1f\x7F".trim_ascii_control(), "X");
+        assert_eq!(" X ".trim_ascii_control(), " X ");
+        assert_eq!(" \rX\n ".trim_ascii_control(), " \rX\n ");
+        assert_eq!(
+            String::from("\t \rX\n \x00").trim_ascii_control(),
+            " \rX\n "
+        );
+        assert_eq!("\u{80}X\u{9f}".trim_ascii_control(), "\u{80}X\u{9f}");
+        assert_eq!("\nαβγ\r\n".trim_ascii_control(), "αβγ");
+        assert_eq!(String::from("asd").trim_ascii_control(), "asd");
+        assert_eq!("\r\n".trim_ascii_control(), "");
+        assert_eq!("".trim_ascii_control(), "");
+    }
+
+    #[test]
+    fn test_trim_ascii_control_sides() {
+        assert_eq!(b"\t X \n".trim_ascii_control_start(), b" X \n");
+        assert_eq!(b"\t X \n".trim_ascii_control_end(), b"\t X ");
+        assert_eq!(b"X\n".trim_ascii_control_start(), b"X\n");
+        assert_eq!(b"\nX".trim_ascii_control_end(), b"\nX");
+        assert_eq!(b"\r\n\x00".trim_ascii_control_start(), b"");
+        assert_eq!(b"\r\n\x00".trim_ascii_control_end(), b"");
+        assert_eq!(b"".trim_ascii_control_start(), b"");
+        assert_eq!(b"".trim_ascii_control_end(), b"");
+
+        assert_eq!("\tαβ\n".trim_ascii_control_start(), "αβ\n");
+        assert_eq!("\tαβ\n".trim_ascii_control_end(), "\tαβ");
+        assert_eq!("\r\n".trim_ascii_control_start(), "");
+        assert_eq!("\r\n".trim_ascii_control_end(), "");
+        assert_eq!("".trim_ascii_control_start(), "");
+        assert_eq!("".trim_ascii_control_end(), "");
+    }
+
+    #[test]
+    fn test_trim_ascii_control_cow() {
+        for clean in ["", "L7C", " αβ ", "a\tb"] {
+            assert!(matches!(
+                clean.trim_ascii_control_cow(),
+                Cow::Borrowed(borrowed) if core::ptr::eq(borrowed, clean)
+            ));
+            assert!(matches!(
+                clean.as_bytes().trim_ascii_control_cow(),
+                Cow::Borrowed(borrowed) if borrowed == clean.as_bytes()
+            ));
+        }
+        for (dirty, trimmed) in [("L7C\n", "L7C"), ("\r\n", ""), ("\t αβ \x7f", " αβ ")] {
+            match dirty.trim_ascii_control_cow() {
+                Cow::Owned(owned) => assert_eq!(owned, trimmed),
+                Cow::Borrowed(_) => panic!("`{:?}` needs trimming", dirty),
+            }
+            match dirty.as_bytes().trim_ascii_control_cow() {
+                Cow::Owned(owned) => assert_eq!(owned, trimmed.as_bytes()),
+                Cow::Borrowed(_) => panic!("`{:?}` needs trimming", dirty),
+            }
+        }
+    }
 }
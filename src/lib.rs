@@ -0,0 +1,38 @@
+//! Expresses numbers with ASCII characters.
+//!
+//! ```
+//! use asciinum::*;
+//!
+//! let converter = AsciiConverter::new(&RadixSettings::new(
+//!     RadixSymbols::Disabled,
+//!     RadixNumbers::All,
+//!     RadixLetters::SensitiveOrdered,
+//! ));
+//! assert_eq!(converter.convert(123456u128), "L7C");
+//! assert_eq!(converter.decode("L7C"), Ok(123456));
+//! ```
+//!
+//! # Features
+//!
+//! * `std` (enabled by default): streaming functions like [`convert_reader`]
+//!   which work on [`std::io`] readers and writers. Without it, the library is
+//!   `no_std` and only needs `alloc`.
+//! * `hash`: `AsciiConverter::short_id`, which gives short tokens for
+//!   arbitrary bytes by converting their (non-cryptographic) hash.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod asciinum;
+#[cfg(feature = "std")]
+mod stream;
+
+pub use crate::asciinum::*;
+#[cfg(feature = "std")]
+pub use crate::stream::*;
+
+// runs examples of the readme as doctests, so that they don't go stale
+#[cfg(all(doctest, feature = "std"))]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
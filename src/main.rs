@@ -1,14 +1,103 @@
-use core::str;
-use std::{self, io::BufRead, process::ExitCode};
+use std::{
+    self,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Cursor, IsTerminal, Write},
+    process::ExitCode,
+};
 
-mod asciinum;
 use asciinum::*;
 
 const CLI_HELP_TEXT: &str = r##"
-Reads numbers from stdin & expresses them with ASCII characters.
+Reads numbers from stdin & expresses them with ASCII characters. Numbers can be
+written in hexadecimal, octal or binary with `0x`, `0o` or `0b` prefixes, and
+their digits can be separated with `_` (e.g. `1_000_000`).
 
 Usage: asciinum {-h,--help}
-       asciinum [RADIXOPT]
+       asciinum [OPTIONS] [RADIXOPT] [NUMBER]...
+       asciinum [OPTIONS] --alphabet FILE [NUMBER]...
+       asciinum [OPTIONS] -d [RADIXOPT]
+       asciinum [OPTIONS] -d --alphabet FILE
+       asciinum [OPTIONS] --base N [NUMBER]...
+       asciinum [OPTIONS] -d --base N
+
+NUMBER: Numbers to convert, instead of reading them from stdin.
+
+--show-corpus: Prints the corpus that RADIXOPT (or `--alphabet`, `--base`)
+ resolves to and its length, then exits without reading any input.
+
+-d, --decode: Reverses the operation; reads ASCII numbers from stdin and prints
+ them as decimal numbers. RADIXOPT needs to be same as the one used while
+ encoding these numbers.
+
+--checksum: Appends a check character to every output number, so that typing
+ errors can be detected. While decoding, this character is expected and
+ verified. It's calculated with the Luhn mod N algorithm.
+
+-s, --split: Splits lines on ASCII whitespace and handles every part as a
+ separate number. Output has one line per number.
+
+--input FILE: Reads records from FILE instead of stdin.
+
+--output FILE: Writes results to FILE instead of stdout. FILE is truncated if
+ it already exists.
+
+--strict: Stops at the first record that can't be parsed or converted, instead
+ of reporting it and continuing with the next one. Exit code is 2 either way.
+
+--keep-going: Reports bad records and continues with the next one. This is the
+ default, and overrides an earlier `--strict`.
+
+--stats: After all input is handled, prints how many records are converted and
+ how many of them failed to stderr, like `converted: 3, errors: 0`. If some of
+ them failed, it also prints the line of the first one, like
+ `converted: 3, errors: 1, first error: line 2`.
+
+--delimiter STR: Separates output records with STR, instead of writing each of
+ them on its own line. STR isn't written after the last record. Without
+ `--delimiter` or `-0`, every record is written on its own line when output is
+ a terminal; pipes and files get records separated with newlines, with no
+ newline after the last one.
+
+--json: Writes every result as a JSON object on its own line (JSON Lines), like
+ `{"input": 61, "output": "z"}`. When decoding, inputs are strings and outputs
+ are numbers.
+
+--verify: Decodes every converted number again, and reports it as a bad record
+ if that doesn't give the same number. Useful for trying out new alphabets.
+
+--uppercase, --lowercase: Writes letters of every output in uppercase or
+ lowercase, for systems that store them case-folded. Unless RADIXOPT letters are
+ insensitive (or the alphabet has letters of a single case), outputs may not
+ decode back to the same numbers; a warning is printed then. Decoding isn't
+ changed.
+
+--max-width N: Reports numbers whose output would be longer than N characters
+ as bad records, instead of converting them. Decoding isn't limited.
+
+-0, --null: Same as `--delimiter` with a NUL character, for `xargs -0`.
+
+--field N: Handles only the Nth field (counting from 1) of every line, like a
+ column of a CSV file. Lines without that field are reported as bad records.
+
+--field-delimiter STR: Separates fields for `--field` with STR. Default is `,`.
+
+--keep-blank: Writes an empty line for every blank input line, comment line and
+ bad record, instead of skipping it, so that output lines line up with input
+ lines (except with `--split`). With `--strict`, the bad record that stops the
+ program writes nothing.
+
+--comment-char CH: Skips lines starting with CH (after leading whitespace)
+ silently, like blank lines. There are no comments by default.
+
+--trim MODE: Selects what is removed from both ends of every line before it's
+ handled; can be one of these: {none,control,whitespace}
+ * none -> nothing, leading and trailing spaces are part of the record
+ * control -> ASCII control characters, like tabs and `\r` (default)
+ * whitespace -> ASCII whitespace, including spaces
+
+--max-line-bytes N: Reports lines longer than N bytes as bad records and skips
+ the rest of them, so that a stream without newlines can't use up the memory.
+ Default is 1048576 (1 MiB).
 
 RADIXOPT: You can change what characters will be used for representing numbers
  with ASCII characters. This can be done with this argument. This option always
@@ -27,7 +116,8 @@ RADIXOPT: You can change what characters will be used for representing numbers
  * d -> disabled: there will be no numbers in output
 
  Third character determines letters; can be one of these: {i,s,o}
- * i -> insensitive: every lowercase alphabetical character in ASCII table
+ * i -> insensitive: every lowercase alphabetical character in ASCII table.
+        decoding accepts uppercase characters as well.
           corpus => abcdefghijklmnopqrstuvwxyz
  * s -> sensitive: same as `i` but includes uppercase characters as well.
         starts with uppercase letters.
@@ -40,36 +130,210 @@ RADIXOPT: You can change what characters will be used for representing numbers
  and generate radix (digits) for output numbers.
 
  Default value for RADIXOPT is: `dao`.
+
+--alphabet FILE: Uses first line of FILE as the corpus, instead of building it
+ from RADIXOPT. Corpus needs to have at least 2 characters and can't contain
+ same character twice.
+
+--base N: Uses classic base N (from 2 to 62) instead of RADIXOPT. Its digits are
+ the first N characters of:
+   0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ
+ e.g. `--base 16` writes hexadecimal numbers with lowercase letters.
+
+Exit status:
+ * 0 -> every record is converted
+ * 1 -> program args are invalid, or input/output can't be read or written
+ * 2 -> some records couldn't be converted (with `--strict`, the first one
+        stops the program)
 "##;
 
-/// Parses radix settings program argument and returns
-/// [`asciinum::RadixSettings`]. If encounters with an error, it returns error
-/// message as String.
-fn parse_radix_arg(arg: &str) -> Result<RadixSettings, String> {
-    let mut chars = arg.chars();
-    if let (Some(s), Some(n), Some(l), None) =
-        (chars.next(), chars.next(), chars.next(), chars.next())
-    {
-        let symbols = match s {
-            'a' => RadixSymbols::All,
-            'u' => RadixSymbols::UnixSafe,
-            'd' => RadixSymbols::Disabled,
-            _ => return Err("first character of radix arg must be one of these: {a,u,d}".into()),
-        };
-        let numbers = match n {
-            'a' => RadixNumbers::All,
-            'd' => RadixNumbers::Disabled,
-            _ => return Err("second character of radix arg must be one of these: {a,d}".into()),
-        };
-        let letters = match l {
-            'i' => RadixLetters::Insensitive,
-            's' => RadixLetters::Sensitive,
-            'o' => RadixLetters::SensitiveOrdered,
-            _ => return Err("third character of radix arg must be one of these: {i,s,o}".into()),
-        };
-        Ok(RadixSettings::new(symbols, numbers, letters))
-    } else {
-        Err("must be 3 characters long".into())
+/// Digits of `--base`, the first N characters are used for base N.
+const BASE_DIGITS: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Default of `--max-line-bytes`.
+const DEFAULT_MAX_LINE_BYTES: usize = 1 << 20;
+
+/// Exit status when every record is converted.
+const EXIT_SUCCESS: u8 = 0;
+/// Exit status for invalid program args and IO errors.
+const EXIT_FAILURE: u8 = 1;
+/// Exit status when some records couldn't be converted.
+const EXIT_FAILED_RECORDS: u8 = 2;
+
+/// Program arguments, except `--help`.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+struct Args {
+    show_corpus: bool,
+    decode: bool,
+    checksum: bool,
+    split: bool,
+    // stop at the first record that can't be converted
+    strict: bool,
+    stats: bool,
+    json: bool,
+    max_width: Option<usize>,
+    case: Option<OutputCase>,
+    // None means DEFAULT_MAX_LINE_BYTES
+    max_line_bytes: Option<usize>,
+    verify: bool,
+    // None means every record is written on its own line
+    delimiter: Option<String>,
+    field: Option<usize>,
+    field_delimiter: Option<String>,
+    keep_blank: bool,
+    comment_char: Option<char>,
+    trim: LineTrim,
+    input: Option<String>,
+    output: Option<String>,
+    alphabet: Option<String>,
+    base: Option<usize>,
+    radix: Option<String>,
+    // numbers given as arguments, these are converted instead of the input
+    numbers: Vec<String>,
+}
+
+/// Parses program arguments (without the program name). If encounters with an
+/// error, it returns error message as String.
+fn parse_args(argv: Vec<String>) -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut positional = Vec::new();
+    let mut argv = argv.into_iter();
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "--show-corpus" => args.show_corpus = true,
+            "-d" | "--decode" => args.decode = true,
+            "--checksum" => args.checksum = true,
+            "-s" | "--split" => args.split = true,
+            "--strict" => args.strict = true,
+            "--keep-going" => args.strict = false,
+            "--stats" => args.stats = true,
+            "--json" => args.json = true,
+            "--verify" => args.verify = true,
+            "--keep-blank" => args.keep_blank = true,
+            "--uppercase" | "--lowercase" => {
+                let case = match arg.as_str() {
+                    "--uppercase" => OutputCase::Upper,
+                    _ => OutputCase::Lower,
+                };
+                if args.case.is_some_and(|other| other != case) {
+                    return Err("`--uppercase` can't be used together with `--lowercase`".into());
+                }
+                args.case = Some(case);
+            }
+            "-0" | "--null" => args.delimiter = Some("\0".into()),
+            "--delimiter" => match argv.next() {
+                Some(delimiter) => args.delimiter = Some(delimiter),
+                None => return Err("`--delimiter` needs a value".into()),
+            },
+            "--max-width" => match argv.next().map(|width| width.parse()) {
+                Some(Ok(width)) => args.max_width = Some(width),
+                _ => return Err("`--max-width` needs a number".into()),
+            },
+            "--max-line-bytes" => match argv.next().map(|max| max.parse()) {
+                Some(Ok(max)) if max > 0 => args.max_line_bytes = Some(max),
+                _ => return Err("`--max-line-bytes` needs a positive number".into()),
+            },
+            "--field" => match argv.next().map(|field| field.parse()) {
+                Some(Ok(field)) if field > 0 => args.field = Some(field),
+                _ => return Err("`--field` needs a number, starting from 1".into()),
+            },
+            "--field-delimiter" => match argv.next() {
+                Some(delimiter) if !delimiter.is_empty() => args.field_delimiter = Some(delimiter),
+                _ => return Err("`--field-delimiter` needs a value".into()),
+            },
+            "--comment-char" => {
+                let value = argv.next().unwrap_or_default();
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => args.comment_char = Some(ch),
+                    _ => return Err("`--comment-char` needs a single character".into()),
+                }
+            }
+            "--trim" => match argv.next().as_deref() {
+                Some("none") => args.trim = LineTrim::None,
+                Some("control") => args.trim = LineTrim::Control,
+                Some("whitespace") => args.trim = LineTrim::Whitespace,
+                _ => return Err("`--trim` needs one of these: {none,control,whitespace}".into()),
+            },
+            "--input" => match argv.next() {
+                Some(path) => args.input = Some(path),
+                None => return Err("`--input` needs a file path".into()),
+            },
+            "--output" => match argv.next() {
+                Some(path) => args.output = Some(path),
+                None => return Err("`--output` needs a file path".into()),
+            },
+            "--alphabet" => match argv.next() {
+                Some(path) => args.alphabet = Some(path),
+                None => return Err("`--alphabet` needs a file path".into()),
+            },
+            "--base" => match argv.next().map(|base| base.parse()) {
+                Some(Ok(base)) if (2..=BASE_DIGITS.len()).contains(&base) => args.base = Some(base),
+                _ => return Err("`--base` needs a number from 2 to 62".into()),
+            },
+            _ => positional.push(arg),
+        }
+    }
+    if args.alphabet.is_some() && args.base.is_some() {
+        return Err("`--alphabet` can't be used together with `--base`".into());
+    }
+    let mut positional = positional.into_iter().peekable();
+    if args.alphabet.is_none() && args.base.is_none() {
+        // RADIXOPT is optional while encoding, so a leading number is an input
+        let is_number = |arg: &String| arg.starts_with(|ch: char| ch.is_ascii_digit() || ch == '+');
+        args.radix = positional.next_if(|arg| args.decode || !is_number(arg));
+    }
+    if args.decode && positional.peek().is_some() {
+        return Err(match (&args.alphabet, args.base) {
+            (Some(_), _) => "`--alphabet` can't be used together with RADIXOPT".into(),
+            (None, Some(_)) => "`--base` can't be used together with RADIXOPT".into(),
+            (None, None) => "too many arguments. use `--help` for more info.".into(),
+        });
+    }
+    args.numbers = positional.collect();
+    if args.input.is_some() && !args.numbers.is_empty() {
+        return Err("`--input` can't be used together with NUMBER arguments".into());
+    }
+    Ok(args)
+}
+
+/// Builds a converter whose corpus is the first line of the file at `path`,
+/// without surrounding ASCII control characters.
+fn read_alphabet(path: &str) -> Result<AsciiConverter, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| format!("couldn't read alphabet file `{}`: {}", path, err))?;
+    let corpus = contents.lines().next().unwrap_or("").trim_ascii_control();
+    AsciiConverter::from_corpus(corpus)
+        .map_err(|err| format!("invalid alphabet in `{}`: {}", path, err))
+}
+
+/// Builds a converter for `--base`, whose corpus is the first `base`
+/// characters of [`BASE_DIGITS`]. `base` must be between 2 and 62.
+fn base_converter(base: usize) -> AsciiConverter {
+    AsciiConverter::from_corpus(&BASE_DIGITS[..base]).expect("base digits are distinct")
+}
+
+/// Builds stream options from program arguments. `is_terminal` tells whether
+/// output goes to a terminal, which picks the delimiter unless one is given.
+fn stream_options(args: &Args, is_terminal: bool) -> StreamOptions {
+    StreamOptions {
+        decode: args.decode,
+        split: args.split,
+        strict: args.strict,
+        delimiter: args
+            .delimiter
+            .clone()
+            .or_else(|| (!is_terminal).then(|| "\n".into())),
+        field: args.field,
+        field_delimiter: args.field_delimiter.clone(),
+        keep_blank: args.keep_blank,
+        comment_char: args.comment_char,
+        json: args.json,
+        max_width: args.max_width,
+        verify: args.verify,
+        trim: args.trim,
+        max_line_bytes: Some(args.max_line_bytes.unwrap_or(DEFAULT_MAX_LINE_BYTES)),
+        case: args.case,
     }
 }
 
@@ -85,129 +349,432 @@ fn main() -> ExitCode {
                     "couldn't parse program arg `{}`: it's not a valid utf-8 string",
                     arg.to_string_lossy(),
                 );
-                return ExitCode::FAILURE;
+                return ExitCode::from(EXIT_FAILURE);
             }
         }
     }
     if argv.iter().any(|arg| arg == "-h" || arg == "--help") {
         println!("{}", CLI_HELP_TEXT.trim());
-        return ExitCode::SUCCESS;
-    }
-    if argv.len() > 1 {
-        eprintln!("too many arguments. use `--help` for more info.");
-        return ExitCode::FAILURE;
+        return ExitCode::from(EXIT_SUCCESS);
     }
+    let args = match parse_args(argv) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::from(EXIT_FAILURE);
+        }
+    };
 
-    let settings = match argv.pop() {
-        Some(arg) => match parse_radix_arg(&arg) {
-            Ok(settings) => settings,
+    let mut converter = match (&args.alphabet, args.base, &args.radix) {
+        (_, Some(base), _) => base_converter(base),
+        (Some(path), None, _) => match read_alphabet(path) {
+            Ok(converter) => converter,
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::from(EXIT_FAILURE);
+            }
+        },
+        (None, None, Some(arg)) => match arg.parse::<RadixSettings>() {
+            Ok(settings) => AsciiConverter::new(&settings),
             Err(err) => {
                 eprintln!("couldn't parse program arg `{}`: {}", arg, err);
-                return ExitCode::FAILURE;
+                return ExitCode::from(EXIT_FAILURE);
             }
         },
-        None => RadixSettings::new(
-            RadixSymbols::Disabled,
-            RadixNumbers::All,
-            RadixLetters::SensitiveOrdered,
-        ),
+        (None, None, None) => AsciiConverter::new(&RadixSettings::default()),
     };
-    let converter = AsciiConverter::new(&settings);
-
-    let mut exit_code = ExitCode::SUCCESS;
-    let mut stdin = std::io::stdin().lock();
-    let mut buffer = Vec::with_capacity(40);
-    loop {
-        match stdin.read_until(b'\n', &mut buffer) {
-            Ok(0) => {
-                // we have reached end of stdin stream
-                break;
+    if args.checksum {
+        converter = converter.with_checksum();
+    }
+    match args.case {
+        Some(case) if !args.decode && !case.is_decodable_with(&converter) => {
+            let flag = match case {
+                OutputCase::Upper => "--uppercase",
+                OutputCase::Lower => "--lowercase",
+            };
+            eprintln!(
+                "warning: `{}` changes characters of the corpus, outputs may not decode back",
+                flag
+            );
+        }
+        _ => {}
+    }
+    if args.show_corpus {
+        println!("{}", converter.corpus());
+        println!("{} characters", converter.corpus().chars().count());
+        return ExitCode::from(EXIT_SUCCESS);
+    }
+
+    let reader: Box<dyn BufRead> = match &args.input {
+        None if !args.numbers.is_empty() => Box::new(Cursor::new(args.numbers.join("\n"))),
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                eprintln!("couldn't open input file `{}`: {}", path, err);
+                return ExitCode::from(EXIT_FAILURE);
             }
-            Ok(_) => {
-                let btrim = buffer.trim_ascii_control();
-                if btrim.is_empty() {
-                    continue;
+        },
+        None => Box::new(std::io::stdin().lock()),
+    };
+    let writer: Box<dyn Write> = match &args.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("couldn't open output file `{}`: {}", path, err);
+                return ExitCode::from(EXIT_FAILURE);
+            }
+        },
+        None => Box::new(std::io::stdout().lock()),
+    };
+    let is_terminal = args.output.is_none() && std::io::stdout().is_terminal();
+    let options = stream_options(&args, is_terminal);
+    let result = convert_reader_with(
+        reader,
+        BufWriter::new(writer),
+        &converter,
+        &options,
+        |err| eprintln!("{}", err),
+    );
+    match result {
+        Ok(summary) => {
+            if args.stats {
+                eprint!(
+                    "converted: {}, errors: {}",
+                    summary.converted, summary.failed
+                );
+                match &summary.first_error {
+                    Some((line, _)) => eprintln!(", first error: line {}", line),
+                    None => eprintln!(),
                 }
-                match str::from_utf8(btrim) {
-                    Ok(line) => match line.parse::<u128>() {
-                        Ok(number) => {
-                            println!("{}", converter.convert(number));
-                        }
-                        Err(err) => {
-                            eprintln!("couldn't parse as integer `{}`: {}", line, err);
-                            exit_code = ExitCode::from(2);
-                        }
-                    },
-                    Err(err) => {
-                        eprintln!(
-                            "couldn't parse ``{}``: {}",
-                            String::from_utf8_lossy(btrim),
-                            err
-                        );
-                        exit_code = ExitCode::from(2);
-                    }
-                };
             }
-            Err(err) => {
-                eprintln!("couldn't read stream: {}", err);
-                exit_code = ExitCode::FAILURE;
-                break;
+            if summary.failed > 0 {
+                ExitCode::from(EXIT_FAILED_RECORDS)
+            } else {
+                ExitCode::from(EXIT_SUCCESS)
             }
         }
-        buffer.clear()
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::from(EXIT_FAILURE)
+        }
     }
-    exit_code
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn argv(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
     #[test]
-    fn test_parse_radix_arg() {
-        assert!(parse_radix_arg("a").is_err());
-        assert!(parse_radix_arg("aa").is_err());
-        assert!(parse_radix_arg("aaix").is_err());
+    fn test_parse_args() {
+        assert_eq!(parse_args(argv(&[])), Ok(Args::default()));
+        assert_eq!(
+            parse_args(argv(&["-d", "aai"])),
+            Ok(Args {
+                decode: true,
+                radix: Some("aai".into()),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["--alphabet", "abc.txt", "--decode"])),
+            Ok(Args {
+                decode: true,
+                alphabet: Some("abc.txt".into()),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["-0"])),
+            Ok(Args {
+                delimiter: Some("\0".into()),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["--delimiter", ","])),
+            Ok(Args {
+                delimiter: Some(",".into()),
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--delimiter"])).is_err());
+        assert_eq!(
+            parse_args(argv(&["--comment-char", "#"])),
+            Ok(Args {
+                comment_char: Some('#'),
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--comment-char"])).is_err());
+        assert_eq!(
+            parse_args(argv(&["--field", "3", "--field-delimiter", "\t"])),
+            Ok(Args {
+                field: Some(3),
+                field_delimiter: Some("\t".into()),
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--field", "0"])).is_err());
+        assert!(parse_args(argv(&["--field", "x"])).is_err());
+        assert!(parse_args(argv(&["--field"])).is_err());
+        assert!(parse_args(argv(&["--field-delimiter", ""])).is_err());
+        assert_eq!(
+            parse_args(argv(&["--max-width", "8"])),
+            Ok(Args {
+                max_width: Some(8),
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--max-width"])).is_err());
+        for max in ["0", "-1", "x"] {
+            assert!(parse_args(argv(&["--max-line-bytes", max])).is_err());
+        }
+        assert_eq!(
+            parse_args(argv(&["--keep-blank"])),
+            Ok(Args {
+                keep_blank: true,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["--verify"])),
+            Ok(Args {
+                verify: true,
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--max-width", "-1"])).is_err());
+        assert_eq!(
+            parse_args(argv(&["--json", "-d"])),
+            Ok(Args {
+                json: true,
+                decode: true,
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--comment-char", "//"])).is_err());
+        assert_eq!(
+            parse_args(argv(&["-d", "--checksum"])),
+            Ok(Args {
+                decode: true,
+                checksum: true,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["--input", "in.txt", "--output", "out.txt"])),
+            Ok(Args {
+                input: Some("in.txt".into()),
+                output: Some("out.txt".into()),
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--input"])).is_err());
+        assert!(parse_args(argv(&["--output"])).is_err());
+        assert_eq!(
+            parse_args(argv(&["--strict"])),
+            Ok(Args {
+                strict: true,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["--stats"])),
+            Ok(Args {
+                stats: true,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["--strict", "--keep-going"])),
+            Ok(Args::default())
+        );
+        assert_eq!(
+            parse_args(argv(&["dai", "--split"])),
+            Ok(Args {
+                split: true,
+                radix: Some("dai".into()),
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--alphabet"])).is_err());
+        assert!(parse_args(argv(&["--alphabet", "abc.txt", "-d", "dao"])).is_err());
+        assert!(parse_args(argv(&["-d", "dao", "dai"])).is_err());
+    }
 
-        assert!(parse_radix_arg("asd").is_err());
-        assert!(parse_radix_arg("efg").is_err());
-        assert!(parse_radix_arg("haha\r\nhehe").is_err());
+    #[test]
+    fn test_parse_args_numbers() {
+        assert_eq!(
+            parse_args(argv(&["dao", "123", "456"])),
+            Ok(Args {
+                radix: Some("dao".into()),
+                numbers: argv(&["123", "456"]),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["123", "+4", "0x1f"])),
+            Ok(Args {
+                numbers: argv(&["123", "+4", "0x1f"]),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["dao", "dai"])),
+            Ok(Args {
+                radix: Some("dao".into()),
+                numbers: argv(&["dai"]),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["--alphabet", "abc.txt", "dao"])),
+            Ok(Args {
+                alphabet: Some("abc.txt".into()),
+                numbers: argv(&["dao"]),
+                ..Args::default()
+            })
+        );
+        assert!(parse_args(argv(&["--input", "in.txt", "123"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_base() {
+        assert_eq!(
+            parse_args(argv(&["--base", "16", "dao"])),
+            Ok(Args {
+                base: Some(16),
+                numbers: argv(&["dao"]),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse_args(argv(&["-d", "--base", "62"])),
+            Ok(Args {
+                decode: true,
+                base: Some(62),
+                ..Args::default()
+            })
+        );
+        for base in ["0", "1", "63", "x", "-2"] {
+            assert!(parse_args(argv(&["--base", base])).is_err());
+        }
+        assert!(parse_args(argv(&["--base"])).is_err());
+        assert!(parse_args(argv(&["--base", "16", "-d", "dao"])).is_err());
+        assert!(parse_args(argv(&["--base", "16", "--alphabet", "abc.txt"])).is_err());
+    }
 
-        assert!(parse_radix_arg("iua").is_err());
-        assert!(parse_radix_arg("suu").is_err());
-        assert!(parse_radix_arg("oud").is_err());
+    #[test]
+    fn test_base_converter() {
+        assert_eq!(base_converter(2).corpus(), "01");
+        assert_eq!(base_converter(16).convert(48879u128), "beef");
+        assert_eq!(base_converter(36).convert(35u128), "z");
+        let converter = base_converter(62);
+        assert_eq!(converter.corpus(), BASE_DIGITS);
+        assert_eq!(converter.convert(61u128), "Z");
+        assert_eq!(converter.convert(62u128), "10");
+    }
 
+    #[test]
+    fn test_parse_args_case() {
+        assert_eq!(
+            parse_args(argv(&["--uppercase"])),
+            Ok(Args {
+                case: Some(OutputCase::Upper),
+                ..Args::default()
+            })
+        );
         assert_eq!(
-            parse_radix_arg("aai").unwrap(),
-            RadixSettings::new(
-                RadixSymbols::All,
-                RadixNumbers::All,
-                RadixLetters::Insensitive
-            )
+            parse_args(argv(&["--lowercase", "dai", "--lowercase"])),
+            Ok(Args {
+                case: Some(OutputCase::Lower),
+                radix: Some("dai".into()),
+                ..Args::default()
+            })
         );
+        assert!(parse_args(argv(&["--uppercase", "--lowercase"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_trim() {
         assert_eq!(
-            parse_radix_arg("udi").unwrap(),
-            RadixSettings::new(
-                RadixSymbols::UnixSafe,
-                RadixNumbers::Disabled,
-                RadixLetters::Insensitive
-            )
+            parse_args(argv(&[])).map(|args| args.trim),
+            Ok(LineTrim::Control)
         );
+        for (arg, trim) in [
+            ("none", LineTrim::None),
+            ("control", LineTrim::Control),
+            ("whitespace", LineTrim::Whitespace),
+        ] {
+            assert_eq!(
+                parse_args(argv(&["--trim", arg])),
+                Ok(Args {
+                    trim,
+                    ..Args::default()
+                })
+            );
+        }
+        assert!(parse_args(argv(&["--trim"])).is_err());
+        assert!(parse_args(argv(&["--trim", "spaces"])).is_err());
+    }
+
+    #[test]
+    fn test_stream_options() {
+        let args = parse_args(argv(&["-0", "--strict"])).expect("valid args");
+        for is_terminal in [false, true] {
+            let options = stream_options(&args, is_terminal);
+            assert_eq!(options.delimiter.as_deref(), Some("\0"));
+            assert!(options.strict);
+        }
+        let args = parse_args(argv(&["--delimiter", ","])).expect("valid args");
+        assert_eq!(stream_options(&args, false).delimiter.as_deref(), Some(","));
+        assert_eq!(stream_options(&args, true).delimiter.as_deref(), Some(","));
+
+        // without a delimiter, only terminals get a newline after every record
+        let args = parse_args(argv(&[])).expect("valid args");
+        assert_eq!(stream_options(&args, true).delimiter, None);
         assert_eq!(
-            parse_radix_arg("das").unwrap(),
-            RadixSettings::new(
-                RadixSymbols::Disabled,
-                RadixNumbers::All,
-                RadixLetters::Sensitive
-            )
+            stream_options(&args, false).delimiter.as_deref(),
+            Some("\n")
         );
+
+        let args = parse_args(argv(&["-d"])).expect("valid args");
         assert_eq!(
-            parse_radix_arg("ado").unwrap(),
-            RadixSettings::new(
-                RadixSymbols::All,
-                RadixNumbers::Disabled,
-                RadixLetters::SensitiveOrdered
-            )
+            stream_options(&args, true),
+            StreamOptions {
+                decode: true,
+                max_line_bytes: Some(DEFAULT_MAX_LINE_BYTES),
+                ..StreamOptions::default()
+            }
         );
+        let args = parse_args(argv(&["--max-line-bytes", "80"])).expect("valid args");
+        assert_eq!(stream_options(&args, true).max_line_bytes, Some(80));
+    }
+
+    #[test]
+    fn test_read_alphabet() {
+        let path = std::env::temp_dir().join(format!("asciinum-alphabet-{}", std::process::id()));
+        let path_str = path.to_str().expect("temp path is utf-8");
+
+        std::fs::write(&path, "αβγδε\r\nthis line is ignored\n").expect("can write temp file");
+        let converter = read_alphabet(path_str).expect("valid alphabet");
+        assert_eq!(converter.convert(123u128), "εεδ");
+
+        std::fs::write(&path, "\t01\n").expect("can write temp file");
+        let converter = read_alphabet(path_str).expect("valid alphabet");
+        assert_eq!(converter.convert(2u128), "10");
+
+        std::fs::write(&path, "abca").expect("can write temp file");
+        assert!(read_alphabet(path_str).is_err());
+
+        std::fs::write(&path, "").expect("can write temp file");
+        assert!(read_alphabet(path_str).is_err());
+
+        std::fs::remove_file(&path).expect("can remove temp file");
+        assert!(read_alphabet(path_str).is_err());
     }
 }
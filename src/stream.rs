@@ -0,0 +1,1381 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    io::{self, BufRead, Read, Write},
+    num::ParseIntError,
+    str,
+};
+
+use crate::asciinum::{AsciiConverter, TrimAsciiControlCharacters};
+
+/// Options of [`convert_reader_with`]. Default options convert every line as
+/// a single number, write one result per line and continue after bad records.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct StreamOptions {
+    /// Decodes ASCII numbers instead of converting numbers.
+    pub decode: bool,
+    /// Splits lines on ASCII whitespace and handles every part as a separate
+    /// record.
+    pub split: bool,
+    /// Stops at the first record that can't be parsed or converted.
+    pub strict: bool,
+    /// Separates output records with this string, instead of writing each of
+    /// them on its own line.
+    pub delimiter: Option<String>,
+    /// Handles only this field (counting from 1) of every line, instead of
+    /// the whole line. Lines without this field are bad records.
+    pub field: Option<usize>,
+    /// Separates fields for `field`, `,` if it's `None`.
+    pub field_delimiter: Option<String>,
+    /// Writes an empty record for every blank line, comment line and bad
+    /// record, instead of skipping it, so that output records line up with
+    /// input lines (unless `split` gives more than one record for a line).
+    /// Empty records aren't counted as converted in the summary. With
+    /// `strict`, the bad record that stops the stream writes nothing.
+    pub keep_blank: bool,
+    /// Skips lines starting with this character (after leading whitespace),
+    /// same as blank lines (see `keep_blank`).
+    pub comment_char: Option<char>,
+    /// Writes every result as a JSON object like `{"input": 61, "output": "z"}`
+    /// instead of the bare result. Inputs of decoding are strings and outputs
+    /// are numbers instead.
+    pub json: bool,
+    /// Refuses to convert numbers whose output would be longer than this many
+    /// characters (see [`AsciiConverter::max_value_for_width`]). Decoded
+    /// records aren't limited.
+    pub max_width: Option<usize>,
+    /// Decodes every converted number again and reports it as a bad record
+    /// if that doesn't give the same number. Decoded records aren't
+    /// verified.
+    pub verify: bool,
+    /// Characters that are removed from both ends of every line before it's
+    /// handled.
+    pub trim: LineTrim,
+    /// Lines longer than this many bytes (without their `\n`) are bad
+    /// records; rest of such a line is skipped without keeping it in memory.
+    /// Lines aren't limited if it's `None`.
+    pub max_line_bytes: Option<usize>,
+    /// Changes case of ASCII letters in converted outputs. Outputs may not
+    /// decode back then, see [`OutputCase::is_decodable_with`]. Decoded
+    /// records aren't changed.
+    pub case: Option<OutputCase>,
+}
+
+/// Case of converted outputs, see [`StreamOptions::case`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum OutputCase {
+    Upper,
+    Lower,
+}
+
+impl OutputCase {
+    /// Returns whether outputs of `converter` still decode to the same
+    /// numbers after changing their case. That's the case when no corpus
+    /// character changes, or when `converter` decodes changed characters to
+    /// the same digits (like [`RadixLetters::Insensitive`]). Check symbols
+    /// aren't considered.
+    ///
+    /// ```
+    /// use asciinum::*;
+    ///
+    /// let converter = AsciiConverter::new(&"dai".parse().unwrap());
+    /// assert!(OutputCase::Upper.is_decodable_with(&converter));
+    /// let converter = AsciiConverter::new(&"dao".parse().unwrap());
+    /// assert!(!OutputCase::Upper.is_decodable_with(&converter));
+    /// ```
+    ///
+    /// [`RadixLetters::Insensitive`]: crate::RadixLetters::Insensitive
+    pub fn is_decodable_with(self, converter: &AsciiConverter) -> bool {
+        converter
+            .corpus()
+            .chars()
+            .all(|ch| converter.digit_for_char(self.apply_char(ch)) == converter.digit_for_char(ch))
+    }
+    fn apply_char(self, ch: char) -> char {
+        match self {
+            OutputCase::Upper => ch.to_ascii_uppercase(),
+            OutputCase::Lower => ch.to_ascii_lowercase(),
+        }
+    }
+    fn apply(self, token: &mut str) {
+        match self {
+            OutputCase::Upper => token.make_ascii_uppercase(),
+            OutputCase::Lower => token.make_ascii_lowercase(),
+        }
+    }
+}
+
+/// How [`convert_reader_with`] cleans lines, see [`StreamOptions::trim`].
+/// Line terminators (`\n` or `\r\n`) are always removed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum LineTrim {
+    /// Lines are used as they are.
+    None,
+    /// ASCII control characters are removed, see
+    /// [`TrimAsciiControlCharacters`]. Spaces are kept.
+    #[default]
+    Control,
+    /// ASCII whitespace is removed, like [`slice::trim_ascii`]. Control
+    /// characters other than whitespace are kept.
+    Whitespace,
+}
+
+impl LineTrim {
+    fn trim(self, line: &[u8]) -> &[u8] {
+        match self {
+            LineTrim::None => line
+                .strip_suffix(b"\r\n")
+                .or_else(|| line.strip_suffix(b"\n"))
+                .unwrap_or(line),
+            LineTrim::Control => line.trim_ascii_control(),
+            LineTrim::Whitespace => line.trim_ascii(),
+        }
+    }
+}
+
+/// Counts of records that are handled by [`convert_reader_with`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct StreamSummary {
+    /// Records that are converted and written to the output.
+    pub converted: usize,
+    /// Records that couldn't be parsed or converted.
+    pub failed: usize,
+    /// Line number (counting from 1) and error message of the first record
+    /// that couldn't be parsed or converted. Every line of the input is
+    /// counted, including blank lines and comments.
+    pub first_error: Option<(usize, String)>,
+}
+
+impl StreamSummary {
+    fn fail(&mut self, line: usize, err: &str) {
+        self.failed += 1;
+        self.first_error
+            .get_or_insert_with(|| (line, err.to_owned()));
+    }
+}
+
+/// Errors that stop [`convert_reader_with`] before the end of the input.
+#[derive(Debug)]
+pub enum StreamError {
+    /// Input couldn't be read.
+    Read(io::Error),
+    /// Output couldn't be written.
+    Write(io::Error),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Read(err) => write!(f, "couldn't read stream: {}", err),
+            StreamError::Write(err) => write!(f, "couldn't write output: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Read(err) | StreamError::Write(err) => Some(err),
+        }
+    }
+}
+
+/// Reasons why [`parse_number`] can fail.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseNumberError {
+    /// Digit separator `_` isn't placed between digits.
+    Separator,
+    /// Digits aren't a valid unsigned integer in `radix`.
+    Digits { err: ParseIntError, radix: u32 },
+}
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNumberError::Separator => {
+                write!(f, "digit separator `_` must be placed between digits")
+            }
+            ParseNumberError::Digits { err, radix: 10 } => write!(f, "{}", err),
+            ParseNumberError::Digits { err, radix } => write!(f, "{} (base {})", err, radix),
+        }
+    }
+}
+
+impl std::error::Error for ParseNumberError {}
+
+/// Parses an input record as unsigned integer. `0x`, `0o` and `0b` prefixes
+/// select hexadecimal, octal and binary bases; anything else is decimal. A
+/// leading `+` and `_` separators between digits (like `1_000_000`) are
+/// allowed.
+///
+/// ```
+/// use asciinum::*;
+///
+/// assert_eq!(parse_number("1_000"), Ok(1000));
+/// assert_eq!(parse_number("+0x1f"), Ok(31));
+/// assert!(parse_number("-1").is_err());
+/// ```
+pub fn parse_number(input: &str) -> Result<u128, ParseNumberError> {
+    let input = input.strip_prefix('+').unwrap_or(input);
+    let (digits, radix) = match input.get(..2) {
+        Some("0x") => (&input[2..], 16),
+        Some("0o") => (&input[2..], 8),
+        Some("0b") => (&input[2..], 2),
+        _ => (input, 10),
+    };
+    // `from_str_radix` accepts a leading sign, but we have already taken ours;
+    // a lone sign gives the same error as any other invalid digit
+    if digits.starts_with(['+', '-']) {
+        let err = u128::from_str_radix(&digits[..1], radix).expect_err("sign isn't a number");
+        return Err(ParseNumberError::Digits { err, radix });
+    }
+    let digits = if digits.contains('_') {
+        if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+            return Err(ParseNumberError::Separator);
+        }
+        Cow::Owned(digits.replace('_', ""))
+    } else {
+        Cow::Borrowed(digits)
+    };
+    u128::from_str_radix(&digits, radix).map_err(|err| ParseNumberError::Digits { err, radix })
+}
+
+/// Same as [`convert_reader_with`] with default options. Bad records are
+/// skipped silently, they are only counted in the summary.
+///
+/// ```
+/// use asciinum::*;
+///
+/// let converter = AsciiConverter::new(&RadixSettings::default());
+/// let mut output = Vec::new();
+/// let summary = convert_reader(&b"123456\nabc\n61\n"[..], &mut output, &converter).unwrap();
+/// assert_eq!(output, b"L7C\nz\n");
+/// assert_eq!(summary.converted, 2);
+/// assert_eq!(summary.failed, 1);
+/// assert_eq!(
+///     summary.first_error,
+///     Some((2, "couldn't parse as integer `abc`: invalid digit found in string".into()))
+/// );
+/// ```
+pub fn convert_reader(
+    reader: impl BufRead,
+    writer: impl Write,
+    converter: &AsciiConverter,
+) -> Result<StreamSummary, StreamError> {
+    convert_reader_with(reader, writer, converter, &StreamOptions::default(), |_| {})
+}
+
+/// Same as [`convert_reader`], but decodes ASCII numbers instead of
+/// converting numbers. Tokens that can't be decoded are skipped silently,
+/// they are only counted in the summary.
+///
+/// ```
+/// use asciinum::*;
+///
+/// let converter = AsciiConverter::new(&RadixSettings::default());
+/// let mut output = Vec::new();
+/// let summary = decode_reader(&b"L7C\n!!\nz\n"[..], &mut output, &converter).unwrap();
+/// assert_eq!(output, b"123456\n61\n");
+/// assert_eq!(summary.converted, 2);
+/// assert_eq!(summary.failed, 1);
+/// ```
+pub fn decode_reader(
+    reader: impl BufRead,
+    writer: impl Write,
+    converter: &AsciiConverter,
+) -> Result<StreamSummary, StreamError> {
+    let options = StreamOptions {
+        decode: true,
+        ..StreamOptions::default()
+    };
+    convert_reader_with(reader, writer, converter, &options, |_| {})
+}
+
+/// Reads records from `reader` line by line, converts (or decodes) them and
+/// writes results to `writer`. Blank lines (unless
+/// [`StreamOptions::keep_blank`] is set), ASCII control characters around
+/// lines (or other characters, see [`StreamOptions::trim`]) and a byte order
+/// mark at the start of the input are ignored.
+///
+/// Records that can't be parsed or converted are reported to `on_error` with
+/// a message, and counted in the returned summary. Only read and write errors
+/// are returned as an error.
+pub fn convert_reader_with(
+    mut reader: impl BufRead,
+    writer: impl Write,
+    converter: &AsciiConverter,
+    options: &StreamOptions,
+    mut on_error: impl FnMut(&str),
+) -> Result<StreamSummary, StreamError> {
+    let mut output = Output::new(writer, options.delimiter.clone());
+    let mut summary = StreamSummary::default();
+    let mut result = Ok(());
+    let mut buffer = Vec::with_capacity(40);
+    let mut first_line = true;
+    let mut line_number = 0;
+    // reading one more byte than the limit tells whether the line is longer
+    let read_limit = options
+        .max_line_bytes
+        .map_or(u64::MAX, |max| (max as u64).saturating_add(1));
+    'lines: loop {
+        buffer.clear();
+        match (&mut reader)
+            .take(read_limit)
+            .read_until(b'\n', &mut buffer)
+        {
+            Ok(0) => {
+                // we have reached end of the stream
+                break;
+            }
+            Ok(_) if buffer.len() as u64 == read_limit && buffer.last() != Some(&b'\n') => {
+                first_line = false;
+                line_number += 1;
+                if let Err(err) = reader.skip_until(b'\n') {
+                    result = Err(StreamError::Read(err));
+                    break;
+                }
+                let err = format!(
+                    "couldn't parse a line: it's longer than {} bytes",
+                    read_limit - 1
+                );
+                on_error(&err);
+                summary.fail(line_number, &err);
+                if options.strict {
+                    break;
+                }
+                if options.keep_blank {
+                    output.write_record("").map_err(StreamError::Write)?;
+                }
+            }
+            Ok(_) => {
+                line_number += 1;
+                let mut bytes = &buffer[..];
+                if first_line {
+                    // files saved on Windows may start with a byte order mark
+                    bytes = bytes.strip_prefix(UTF8_BOM).unwrap_or(bytes);
+                    first_line = false;
+                }
+                let btrim = options.trim.trim(bytes);
+                if btrim.is_empty() || is_comment(btrim, options.comment_char) {
+                    if options.keep_blank {
+                        output.write_record("").map_err(StreamError::Write)?;
+                    }
+                    continue;
+                }
+                let line = str::from_utf8(btrim)
+                    .map_err(|err| {
+                        format!(
+                            "couldn't parse ``{}``: {}",
+                            String::from_utf8_lossy(btrim),
+                            err
+                        )
+                    })
+                    .and_then(|line| select_field(line, options));
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => {
+                        on_error(&err);
+                        summary.fail(line_number, &err);
+                        if options.strict {
+                            break;
+                        }
+                        if options.keep_blank {
+                            output.write_record("").map_err(StreamError::Write)?;
+                        }
+                        continue;
+                    }
+                };
+                let split = options.split.then(|| line.split_ascii_whitespace());
+                let whole = (!options.split).then_some(line);
+                for record in split.into_iter().flatten().chain(whole) {
+                    match convert_record(record, options, converter) {
+                        Ok(record) => {
+                            output.write_record(&record).map_err(StreamError::Write)?;
+                            summary.converted += 1;
+                        }
+                        Err(err) => {
+                            on_error(&err);
+                            summary.fail(line_number, &err);
+                            if options.strict {
+                                break 'lines;
+                            }
+                            if options.keep_blank {
+                                output.write_record("").map_err(StreamError::Write)?;
+                            }
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                result = Err(StreamError::Read(err));
+                break;
+            }
+        }
+    }
+    // results before a read error are still written
+    output.flush().map_err(StreamError::Write)?;
+    result.map(|()| summary)
+}
+
+/// UTF-8 encoding of the byte order mark, U+FEFF.
+const UTF8_BOM: &[u8] = "\u{feff}".as_bytes();
+
+/// Returns whether `line` starts with `comment_char`, ignoring leading
+/// whitespace. Only the comment character needs to be valid UTF-8, so that
+/// comments can contain anything.
+fn is_comment(line: &[u8], comment_char: Option<char>) -> bool {
+    comment_char.is_some_and(|ch| {
+        line.trim_ascii_start()
+            .starts_with(ch.encode_utf8(&mut [0; 4]).as_bytes())
+    })
+}
+
+/// Returns the field of `line` that `options` selects, with ASCII whitespace
+/// around it removed, or the whole line if there's no field selected.
+fn select_field<'a>(line: &'a str, options: &StreamOptions) -> Result<&'a str, String> {
+    let Some(field) = options.field else {
+        return Ok(line);
+    };
+    let delimiter = options.field_delimiter.as_deref().unwrap_or(",");
+    field
+        .checked_sub(1)
+        .and_then(|index| line.split(delimiter).nth(index))
+        .map(str::trim_ascii)
+        .ok_or_else(|| format!("couldn't find field {} in `{}`", field, line))
+}
+
+/// Converts (or decodes, depending on `options`) a single record. If
+/// encounters with an error, it returns error message as String.
+fn convert_record(
+    record: &str,
+    options: &StreamOptions,
+    converter: &AsciiConverter,
+) -> Result<String, String> {
+    if options.decode {
+        match converter.decode(record) {
+            Ok(number) if options.json => {
+                let mut object = String::from("{\"input\": ");
+                push_json_string(&mut object, record);
+                object.push_str(&format!(", \"output\": {}}}", number));
+                Ok(object)
+            }
+            Ok(number) => Ok(number.to_string()),
+            Err(err) => Err(format!("couldn't decode `{}`: {}", record, err)),
+        }
+    } else {
+        let number = parse_number(record)
+            .map_err(|err| format!("couldn't parse as integer `{}`: {}", record, err))?;
+        if let Some(max_width) = options.max_width {
+            let width = converter.encoded_len(number);
+            if width > max_width {
+                return Err(format!(
+                    "couldn't convert `{}`: needs {} characters, more than maximum width {}",
+                    record, width, max_width
+                ));
+            }
+        }
+        let mut token = converter.convert(number);
+        if let Some(case) = options.case {
+            case.apply(&mut token);
+        }
+        if options.verify {
+            verify_roundtrip(number, &token, converter.decode(&token))?;
+        }
+        if options.json {
+            let mut object = format!("{{\"input\": {}, \"output\": ", number);
+            push_json_string(&mut object, &token);
+            object.push('}');
+            Ok(object)
+        } else {
+            Ok(token)
+        }
+    }
+}
+
+/// Checks that `token`, the output of `number`, decodes back to `number`.
+fn verify_roundtrip(
+    number: u128,
+    token: &str,
+    decoded: Result<u128, crate::asciinum::Error>,
+) -> Result<(), String> {
+    match decoded {
+        Ok(decoded) if decoded == number => Ok(()),
+        Ok(decoded) => Err(format!(
+            "couldn't verify `{}`: its output `{}` decodes to {}",
+            number, token, decoded
+        )),
+        Err(err) => Err(format!(
+            "couldn't verify `{}`: its output `{}` can't be decoded: {}",
+            number, token, err
+        )),
+    }
+}
+
+/// Appends `value` to `out` as a quoted JSON string.
+fn push_json_string(out: &mut String, value: &str) {
+    use fmt::Write as _;
+
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if ch.is_control() => {
+                write!(out, "\\u{:04x}", ch as u32).expect("writing to String doesn't fail")
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes output records, either line by line or separated with a delimiter.
+struct Output<W: Write> {
+    writer: W,
+    delimiter: Option<String>,
+    records: usize,
+}
+
+impl<W: Write> Output<W> {
+    fn new(writer: W, delimiter: Option<String>) -> Self {
+        Self {
+            writer,
+            delimiter,
+            records: 0,
+        }
+    }
+    fn write_record(&mut self, record: &str) -> io::Result<()> {
+        match &self.delimiter {
+            // delimiter goes between records, so there's none after the last one
+            Some(delimiter) if self.records > 0 => write!(self.writer, "{}{}", delimiter, record),
+            Some(_) => write!(self.writer, "{}", record),
+            None => writeln!(self.writer, "{}", record),
+        }?;
+        self.records += 1;
+        Ok(())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciinum::RadixSettings;
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("0"), Ok(0));
+        assert_eq!(parse_number("123456"), Ok(123456));
+        assert_eq!(parse_number("0123"), Ok(123));
+        assert_eq!(parse_number("+5"), Ok(5));
+        assert_eq!(
+            parse_number("340282366920938463463374607431768211455"),
+            Ok(u128::MAX)
+        );
+        assert_eq!(parse_number("0x1f"), Ok(0x1f));
+        assert_eq!(parse_number("0xDEADbeef"), Ok(0xdeadbeef));
+        assert_eq!(
+            parse_number("0xffffffffffffffffffffffffffffffff"),
+            Ok(u128::MAX)
+        );
+        assert_eq!(parse_number("0o17"), Ok(0o17));
+        assert_eq!(parse_number("0b101"), Ok(0b101));
+        assert_eq!(parse_number("0b0"), Ok(0));
+
+        assert!(parse_number("").is_err());
+        assert!(parse_number("12a").is_err());
+        assert!(parse_number("-1").is_err());
+        assert!(parse_number("0x").is_err());
+        assert!(parse_number("0x1g").is_err());
+        assert!(parse_number("0x+1").is_err());
+        assert!(parse_number("0o8").is_err());
+        assert!(parse_number("0b102").is_err());
+        assert!(parse_number("0X1f").is_err());
+        assert!(parse_number("0x1ffffffffffffffffffffffffffffffff").is_err());
+        assert_eq!(
+            parse_number("0b2").map_err(|err| err.to_string()),
+            Err("invalid digit found in string (base 2)".into())
+        );
+        assert_eq!(
+            parse_number("-1").map_err(|err| err.to_string()),
+            Err("invalid digit found in string".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_number_separators() {
+        assert_eq!(parse_number("1_000_000"), Ok(1_000_000));
+        assert_eq!(parse_number("1_2_3"), Ok(123));
+        assert_eq!(parse_number("+1_000"), Ok(1000));
+        assert_eq!(parse_number("+0"), Ok(0));
+        assert_eq!(parse_number("0xff_ff"), Ok(0xffff));
+        assert_eq!(parse_number("+0b1010_1010"), Ok(0b1010_1010));
+
+        assert!(parse_number("1__2").is_err());
+        assert_eq!(parse_number("_1"), Err(ParseNumberError::Separator));
+        assert_eq!(parse_number("1_"), Err(ParseNumberError::Separator));
+        assert!(parse_number("_").is_err());
+        assert!(parse_number("0x_ff").is_err());
+        assert!(parse_number("0xff_").is_err());
+        assert!(parse_number("+").is_err());
+        assert!(parse_number("++1").is_err());
+        assert!(parse_number("+-1").is_err());
+        assert!(parse_number("+_1").is_err());
+        assert!(parse_number("1+").is_err());
+        assert!(parse_number("1_+2").is_err());
+    }
+
+    fn summary(converted: usize, failed: usize) -> StreamSummary {
+        StreamSummary {
+            converted,
+            failed,
+            first_error: None,
+        }
+    }
+
+    /// Runs with the default converter. Only counts of the summary are
+    /// returned, see `test_convert_reader_first_error` for the first error.
+    fn run_str(input: &str, options: &StreamOptions) -> (String, StreamSummary) {
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let mut output = Vec::new();
+        let summary =
+            convert_reader_with(input.as_bytes(), &mut output, &converter, options, |_| {})
+                .expect("in-memory streams don't fail");
+        let summary = StreamSummary {
+            first_error: None,
+            ..summary
+        };
+        (String::from_utf8(output).expect("output is utf-8"), summary)
+    }
+
+    #[test]
+    fn test_convert_reader() {
+        let options = StreamOptions::default();
+        assert_eq!(
+            run_str("123456\n\n0\r\n61\n", &options),
+            ("L7C\n0\nz\n".into(), summary(3, 0))
+        );
+        assert_eq!(
+            run_str("123456\nabc\n0", &options),
+            ("L7C\n0\n".into(), summary(2, 1))
+        );
+        assert_eq!(run_str("1 2\n", &options), ("".into(), summary(0, 1)));
+        assert_eq!(run_str("\u{7f}\n\n", &options), ("".into(), summary(0, 0)));
+
+        let options = StreamOptions {
+            decode: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("L7C\n!\nz\n", &options),
+            ("123456\n61\n".into(), summary(2, 1))
+        );
+
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let mut output = Vec::new();
+        assert_eq!(
+            convert_reader("0x3d\n+5".as_bytes(), &mut output, &converter)
+                .expect("in-memory streams don't fail"),
+            summary(2, 0)
+        );
+        assert_eq!(output, b"z\n5\n");
+    }
+
+    #[test]
+    fn test_convert_reader_no_final_newline() {
+        for trim in [LineTrim::None, LineTrim::Control, LineTrim::Whitespace] {
+            let options = StreamOptions {
+                trim,
+                ..StreamOptions::default()
+            };
+            assert_eq!(run_str("123", &options), ("1z\n".into(), summary(1, 0)));
+            assert_eq!(
+                run_str("123456\n61", &options),
+                ("L7C\nz\n".into(), summary(2, 0))
+            );
+        }
+        let options = StreamOptions {
+            split: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("1 2\n61 123", &options),
+            ("1\n2\nz\n1z\n".into(), summary(4, 0))
+        );
+        let options = StreamOptions {
+            decode: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("L7C\r\nz", &options),
+            ("123456\n61\n".into(), summary(2, 0))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_max_line_bytes() {
+        let options = StreamOptions {
+            max_line_bytes: Some(6),
+            ..StreamOptions::default()
+        };
+        // limit doesn't include the newline
+        assert_eq!(
+            run_str("123456\n1234567\n61\n", &options),
+            ("L7C\nz\n".into(), summary(2, 1))
+        );
+        assert_eq!(
+            run_str("61\n12345678901234567890", &options),
+            ("z\n".into(), summary(1, 1))
+        );
+        assert_eq!(
+            run_str("1234567\n61\n", &StreamOptions::default()),
+            ("5aAg\nz\n".into(), summary(2, 0))
+        );
+
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let mut errors = Vec::new();
+        let mut output = Vec::new();
+        let options = StreamOptions {
+            max_line_bytes: Some(1024),
+            ..StreamOptions::default()
+        };
+        // a line of 64 MiB is skipped a chunk at a time
+        let reader = io::BufReader::new(io::repeat(b'1').take(64 << 20).chain(&b"\n61\n"[..]));
+        let result = convert_reader_with(reader, &mut output, &converter, &options, |err| {
+            errors.push(err.to_owned())
+        });
+        assert_eq!(
+            result.expect("in-memory streams don't fail"),
+            StreamSummary {
+                first_error: Some((1, errors[0].clone())),
+                ..summary(1, 1)
+            }
+        );
+        assert_eq!(output, b"z\n");
+        assert_eq!(
+            errors,
+            ["couldn't parse a line: it's longer than 1024 bytes"]
+        );
+
+        let options = StreamOptions {
+            strict: true,
+            max_line_bytes: Some(2),
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("61\n123\n61\n", &options),
+            ("z\n".into(), summary(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_case() {
+        let options = |case| StreamOptions {
+            case: Some(case),
+            ..StreamOptions::default()
+        };
+        let input = "123456\n61\n";
+        assert_eq!(
+            run_str(input, &options(OutputCase::Upper)),
+            ("L7C\nZ\n".into(), summary(2, 0))
+        );
+        assert_eq!(
+            run_str(input, &options(OutputCase::Lower)),
+            ("l7c\nz\n".into(), summary(2, 0))
+        );
+        // changed outputs don't decode back, which verification reports
+        let options = StreamOptions {
+            verify: true,
+            ..options(OutputCase::Lower)
+        };
+        assert_eq!(run_str(input, &options), ("z\n".into(), summary(1, 1)));
+        // decoded records don't change
+        let options = StreamOptions {
+            decode: true,
+            ..options
+        };
+        assert_eq!(run_str("z\n", &options), ("61\n".into(), summary(1, 0)));
+
+        // lowercase letters are already lowercase
+        let converter = AsciiConverter::new(&"dai".parse().expect("valid RADIXOPT"));
+        let mut output = Vec::new();
+        let options = StreamOptions {
+            case: Some(OutputCase::Lower),
+            ..StreamOptions::default()
+        };
+        convert_reader_with(&b"123456\n"[..], &mut output, &converter, &options, |_| {})
+            .expect("in-memory streams don't fail");
+        assert_eq!(
+            output,
+            format!("{}\n", converter.convert(123456u128)).as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_output_case_is_decodable_with() {
+        for radix in ["dai", "aai", "ddi"] {
+            let converter = AsciiConverter::new(&radix.parse().expect("valid RADIXOPT"));
+            assert!(OutputCase::Upper.is_decodable_with(&converter));
+            assert!(OutputCase::Lower.is_decodable_with(&converter));
+        }
+        for radix in ["dao", "das"] {
+            let converter = AsciiConverter::new(&radix.parse().expect("valid RADIXOPT"));
+            assert!(!OutputCase::Upper.is_decodable_with(&converter));
+            assert!(!OutputCase::Lower.is_decodable_with(&converter));
+        }
+        // crockford decodes lowercase letters too
+        let converter = AsciiConverter::crockford_base32();
+        assert!(OutputCase::Lower.is_decodable_with(&converter));
+        let converter = AsciiConverter::from_corpus("0123456789abcdef").expect("valid corpus");
+        assert!(OutputCase::Lower.is_decodable_with(&converter));
+        assert!(!OutputCase::Upper.is_decodable_with(&converter));
+    }
+
+    #[test]
+    fn test_convert_reader_first_error() {
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let run = |input: &str, options: &StreamOptions| {
+            let mut output = Vec::new();
+            convert_reader_with(input.as_bytes(), &mut output, &converter, options, |_| {})
+                .expect("in-memory streams don't fail")
+        };
+        let options = StreamOptions::default();
+        let summary = run("1\n2\nabc\n4\n-5\n", &options);
+        assert_eq!((summary.converted, summary.failed), (3, 2));
+        assert_eq!(
+            summary.first_error,
+            Some((
+                3,
+                "couldn't parse as integer `abc`: invalid digit found in string".into()
+            ))
+        );
+        assert_eq!(run("1\n2\n", &options).first_error, None);
+
+        // blank lines and comments are counted too
+        let options = StreamOptions {
+            comment_char: Some('#'),
+            split: true,
+            ..StreamOptions::default()
+        };
+        let summary = run("# ids\n\n1 2\n3 x y\n", &options);
+        assert_eq!(summary.failed, 2);
+        assert_eq!(summary.first_error.map(|(line, _)| line), Some(4));
+
+        let options = StreamOptions {
+            max_line_bytes: Some(2),
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run("1\n123\n", &options).first_error,
+            Some((2, "couldn't parse a line: it's longer than 2 bytes".into()))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_split() {
+        let options = StreamOptions {
+            split: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("1 2\t3\n  4   5 \t\t6\n\n7\n", &options),
+            ("1\n2\n3\n4\n5\n6\n7\n".into(), summary(7, 0))
+        );
+        assert_eq!(
+            run_str("123456 x 61\n0\n", &options),
+            ("L7C\nz\n0\n".into(), summary(3, 1))
+        );
+
+        let options = StreamOptions {
+            decode: true,
+            split: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("L7C  z\n", &options),
+            ("123456\n61\n".into(), summary(2, 0))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_strict() {
+        let options = StreamOptions {
+            strict: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("123456\nx\n61\n", &options),
+            ("L7C\n".into(), summary(1, 1))
+        );
+        assert_eq!(
+            run_str("123456\n61\n", &options),
+            ("L7C\nz\n".into(), summary(2, 0))
+        );
+
+        let options = StreamOptions {
+            strict: true,
+            split: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("123456 x 61\n0\n", &options),
+            ("L7C\n".into(), summary(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_delimiter() {
+        let options = StreamOptions {
+            delimiter: Some("\0".into()),
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("123456\n0\n61\n", &options),
+            (["L7C", "0", "z"].join("\0"), summary(3, 0))
+        );
+        assert_eq!(run_str("61\n", &options), ("z".into(), summary(1, 0)));
+        assert_eq!(run_str("", &options), ("".into(), summary(0, 0)));
+        // failed records don't leave stray delimiters behind
+        assert_eq!(
+            run_str("x\n123456\nx\n61\nx\n", &options),
+            ("L7C\0z".into(), summary(2, 3))
+        );
+
+        let options = StreamOptions {
+            delimiter: Some(", ".into()),
+            split: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("123456 0\n61\n", &options),
+            ("L7C, 0, z".into(), summary(3, 0))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_field() {
+        let options = StreamOptions {
+            field: Some(3),
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("a,b,123456,d\nx,y, 61\nshort,line\n,,0\n", &options),
+            ("L7C\nz\n0\n".into(), summary(3, 1))
+        );
+
+        let options = StreamOptions {
+            field: Some(1),
+            field_delimiter: Some("\t".into()),
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("123456\tfoo\n61\n61,62\tbar\n", &options),
+            ("L7C\nz\n".into(), summary(2, 1))
+        );
+        let options = StreamOptions {
+            field: Some(2),
+            ..options
+        };
+        assert_eq!(
+            run_str("foo\t123456\nbar\t61\tbaz\n", &options),
+            ("L7C\nz\n".into(), summary(2, 0))
+        );
+
+        // selected field can be split further
+        let options = StreamOptions {
+            field: Some(2),
+            field_delimiter: Some(";".into()),
+            split: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("ids;123456 61;x\n", &options),
+            ("L7C\nz\n".into(), summary(2, 0))
+        );
+
+        let mut errors = Vec::new();
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let options = StreamOptions {
+            field: Some(3),
+            strict: true,
+            ..StreamOptions::default()
+        };
+        let result = convert_reader_with(
+            &b"a,b\n1,2,3\n"[..],
+            &mut Vec::new(),
+            &converter,
+            &options,
+            |err| errors.push(err.to_owned()),
+        );
+        assert_eq!(
+            result.expect("in-memory streams don't fail"),
+            StreamSummary {
+                first_error: Some((1, errors[0].clone())),
+                ..summary(0, 1)
+            }
+        );
+        assert_eq!(errors, ["couldn't find field 3 in `a,b`"]);
+
+        let options = StreamOptions {
+            field: Some(0),
+            ..StreamOptions::default()
+        };
+        assert_eq!(run_str("1,2\n", &options), ("".into(), summary(0, 1)));
+    }
+
+    #[test]
+    fn test_convert_reader_keep_blank() {
+        let input = "123456\n\n\r\n61\n\n";
+        assert_eq!(
+            run_str(input, &StreamOptions::default()),
+            ("L7C\nz\n".into(), summary(2, 0))
+        );
+        let options = StreamOptions {
+            keep_blank: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str(input, &options),
+            ("L7C\n\n\nz\n\n".into(), summary(2, 0))
+        );
+        // comments and bad records are empty records too, one for each line
+        let options = StreamOptions {
+            comment_char: Some('#'),
+            max_line_bytes: Some(8),
+            ..options
+        };
+        let input = "#ids\n\nabc\n61\n123456789\n-1\n0\n";
+        let (output, counts) = run_str(input, &options);
+        assert_eq!(output, "\n\n\nz\n\n\n0\n");
+        assert_eq!(output.lines().count(), input.lines().count());
+        assert_eq!(counts, summary(2, 3));
+        let options = StreamOptions {
+            field: Some(2),
+            ..options
+        };
+        assert_eq!(
+            run_str("a,61\nb\nc,0\n", &options),
+            ("z\n\n0\n".into(), summary(2, 1))
+        );
+        // the bad record that stops the stream writes nothing
+        let strict = StreamOptions {
+            strict: true,
+            ..options.clone()
+        };
+        assert_eq!(
+            run_str("a,61\nb\nc,0\n", &strict),
+            ("z\n".into(), summary(1, 1))
+        );
+        let options = StreamOptions {
+            delimiter: Some(",".into()),
+            field: None,
+            ..options
+        };
+        assert_eq!(
+            run_str("123456\n\n61\n", &options),
+            ("L7C,,z".into(), summary(2, 0))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_trim() {
+        let options = |trim| StreamOptions {
+            trim,
+            ..StreamOptions::default()
+        };
+        let input = "  123  \n\t123\t\n";
+        assert_eq!(
+            run_str(input, &options(LineTrim::None)),
+            ("".into(), summary(0, 2))
+        );
+        assert_eq!(
+            run_str(input, &options(LineTrim::Control)),
+            ("1z\n".into(), summary(1, 1))
+        );
+        assert_eq!(
+            run_str(input, &options(LineTrim::Whitespace)),
+            ("1z\n1z\n".into(), summary(2, 0))
+        );
+        assert_eq!(StreamOptions::default(), options(LineTrim::Control));
+
+        // spaces can be digits too, then they are meaningful
+        let converter = AsciiConverter::from_corpus(" x").expect("valid corpus");
+        let decode = |trim| {
+            let options = StreamOptions {
+                decode: true,
+                ..options(trim)
+            };
+            let mut output = Vec::new();
+            convert_reader_with(&b"  x  \r\n"[..], &mut output, &converter, &options, |_| {})
+                .expect("in-memory streams don't fail");
+            output
+        };
+        assert_eq!(decode(LineTrim::None), b"4\n");
+        assert_eq!(decode(LineTrim::Control), b"4\n");
+        assert_eq!(decode(LineTrim::Whitespace), b"1\n");
+    }
+
+    #[test]
+    fn test_convert_reader_comments() {
+        let options = StreamOptions {
+            comment_char: Some('#'),
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str(
+                "# ids\n123456\n  # indented\n\n61 # trailing\n0\n",
+                &options
+            ),
+            ("L7C\n0\n".into(), summary(2, 1))
+        );
+        // without the option, comments are bad records
+        assert_eq!(
+            run_str("# ids\n123456\n", &StreamOptions::default()),
+            ("L7C\n".into(), summary(1, 1))
+        );
+
+        // comments don't need to be valid UTF-8
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let mut output = Vec::new();
+        let result = convert_reader_with(
+            &b"# \xff\xfe\n61\n"[..],
+            &mut output,
+            &converter,
+            &options,
+            |_| {},
+        );
+        assert_eq!(result.expect("in-memory streams don't fail"), summary(1, 0));
+        assert_eq!(output, b"z\n");
+
+        // multibyte comment characters work too
+        let options = StreamOptions {
+            comment_char: Some('§'),
+            decode: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("§ L7C\nL7C\n", &options),
+            ("123456\n".into(), summary(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_json() {
+        let options = StreamOptions {
+            json: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("123456\nabc\n0\n", &options),
+            (
+                "{\"input\": 123456, \"output\": \"L7C\"}\n{\"input\": 0, \"output\": \"0\"}\n"
+                    .into(),
+                summary(2, 1)
+            )
+        );
+
+        // `"` and `\` are symbols of this corpus
+        let converter = AsciiConverter::new(&"aao".parse().unwrap());
+        let mut output = Vec::new();
+        let result =
+            convert_reader_with(&b"1\n23\n"[..], &mut output, &converter, &options, |_| {});
+        assert_eq!(result.expect("in-memory streams don't fail"), summary(2, 0));
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"input\": 1, \"output\": \"\\\"\"}\n{\"input\": 23, \"output\": \"\\\\\"}\n"
+        );
+
+        let options = StreamOptions {
+            json: true,
+            decode: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("L7C\n", &options),
+            (
+                "{\"input\": \"L7C\", \"output\": 123456}\n".into(),
+                summary(1, 0)
+            )
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_verify() {
+        let options = StreamOptions {
+            verify: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str(
+                "0\n123456\n340282366920938463463374607431768211455\nabc\n",
+                &options
+            ),
+            ("0\nL7C\n7t42bDG5jpsS9t8Tw7cqO7\n".into(), summary(3, 1))
+        );
+
+        assert_eq!(verify_roundtrip(61, "z", Ok(61)), Ok(()));
+        assert_eq!(
+            verify_roundtrip(61, "z", Ok(62)),
+            Err("couldn't verify `61`: its output `z` decodes to 62".into())
+        );
+        assert_eq!(
+            verify_roundtrip(61, "", Err(crate::asciinum::Error::Empty)),
+            Err(
+                "couldn't verify `61`: its output `` can't be decoded: cannot decode empty string"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_push_json_string() {
+        let mut out = String::new();
+        push_json_string(&mut out, "a\"b\\c\nd\u{1}e\u{7f}ö");
+        assert_eq!(out, r#""a\"b\\c\nd\u0001e\u007fö""#);
+    }
+
+    #[test]
+    fn test_convert_reader_bom() {
+        let options = StreamOptions::default();
+        assert_eq!(
+            run_str("\u{feff}123\n456\n", &options),
+            ("1z\n7G\n".into(), summary(2, 0))
+        );
+        // only the first line may start with it
+        assert_eq!(
+            run_str("123\n\u{feff}456\n", &options),
+            ("1z\n".into(), summary(1, 1))
+        );
+        assert_eq!(
+            run_str("\u{feff}\n123\n", &options),
+            ("1z\n".into(), summary(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_max_width() {
+        let options = StreamOptions {
+            max_width: Some(2),
+            ..StreamOptions::default()
+        };
+        // 3843 is the largest number with 2 digits in base 62
+        let mut errors = Vec::new();
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        assert_eq!(converter.max_value_for_width(2), Ok(Some(3843)));
+        let mut output = Vec::new();
+        let result = convert_reader_with(
+            &b"0\n3843\n3844\n"[..],
+            &mut output,
+            &converter,
+            &options,
+            |err| errors.push(err.to_owned()),
+        );
+        assert_eq!(
+            result.expect("in-memory streams don't fail"),
+            StreamSummary {
+                first_error: Some((3, errors[0].clone())),
+                ..summary(2, 1)
+            }
+        );
+        assert_eq!(output, b"0\nzz\n");
+        assert_eq!(
+            errors,
+            ["couldn't convert `3844`: needs 3 characters, more than maximum width 2"]
+        );
+
+        // decoded records aren't limited
+        let options = StreamOptions {
+            decode: true,
+            ..options
+        };
+        assert_eq!(
+            run_str("L7C\n", &options),
+            ("123456\n".into(), summary(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_decode_reader() {
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let mut output = Vec::new();
+        let input = b"L7C\n\nz\t\nL7C!\n\xff\n0\nzzzzzzzzzzzzzzzzzzzzzzzz\n10\n";
+        let result = decode_reader(&input[..], &mut output, &converter);
+        let result = result.expect("in-memory streams don't fail");
+        assert_eq!((result.converted, result.failed), (4, 3));
+        assert_eq!(result.first_error.map(|(line, _)| line), Some(4));
+        assert_eq!(output, b"123456\n61\n0\n62\n");
+
+        // same as the decode option
+        let options = StreamOptions {
+            decode: true,
+            strict: true,
+            ..StreamOptions::default()
+        };
+        assert_eq!(
+            run_str("L7C\nL7C!\nz\n", &options),
+            ("123456\n".into(), summary(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_convert_reader_errors() {
+        struct Broken;
+
+        impl io::Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+        }
+
+        impl Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let converter = AsciiConverter::new(&RadixSettings::default());
+        let mut errors = Vec::new();
+        let mut output = Vec::new();
+        let result = convert_reader_with(
+            &b"123456\nabc\n\xff\n-1\n"[..],
+            &mut output,
+            &converter,
+            &StreamOptions::default(),
+            |err| errors.push(err.to_owned()),
+        );
+        assert_eq!(
+            result.expect("in-memory streams don't fail"),
+            StreamSummary {
+                first_error: Some((2, errors[0].clone())),
+                ..summary(1, 3)
+            }
+        );
+        assert_eq!(output, b"L7C\n");
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].contains("`abc`"));
+        assert!(errors[1].contains("``\u{fffd}``"));
+        assert!(errors[2].contains("`-1`"));
+
+        // records that are read before a read error are still written
+        let mut output = Vec::new();
+        let reader = io::BufReader::new(io::Read::chain(&b"61\n"[..], Broken));
+        assert!(matches!(
+            convert_reader(reader, &mut output, &converter),
+            Err(StreamError::Read(_))
+        ));
+        assert_eq!(output, b"z\n");
+
+        assert!(matches!(
+            convert_reader(&b"61\n"[..], Broken, &converter),
+            Err(StreamError::Write(_))
+        ));
+    }
+}
@@ -0,0 +1,438 @@
+use std::{
+    io::{ErrorKind, Write},
+    process::{Command, Output, Stdio},
+};
+
+/// Runs the binary with `args`, feeding `input` to its stdin.
+fn asciinum(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_asciinum"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("can run asciinum");
+    // the binary doesn't read stdin when numbers come from elsewhere, and it
+    // may exit before all of `input` is written
+    match child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(input.as_bytes())
+    {
+        Err(err) if err.kind() != ErrorKind::BrokenPipe => panic!("can't write to stdin: {}", err),
+        _ => {}
+    }
+    child.wait_with_output().expect("asciinum finishes")
+}
+
+#[test]
+fn test_keep_going() {
+    const INPUT: &str = "123456\nabc\n61\n-1\n0\n";
+    for args in [&[][..], &["--keep-going"], &["--strict", "--keep-going"]] {
+        let output = asciinum(args, INPUT);
+        assert_eq!(output.status.code(), Some(2));
+        assert_eq!(output.stdout, b"L7C\nz\n0");
+        let stderr = String::from_utf8(output.stderr).expect("stderr is utf-8");
+        assert_eq!(stderr.lines().count(), 2);
+        assert!(stderr.contains("`abc`"));
+        assert!(stderr.contains("`-1`"));
+    }
+}
+
+#[test]
+fn test_strict() {
+    let output = asciinum(&["--strict"], "123456\nabc\n61\n-1\n0\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C");
+    let stderr = String::from_utf8(output.stderr).expect("stderr is utf-8");
+    assert_eq!(stderr.lines().count(), 1);
+    assert!(stderr.contains("`abc`"));
+
+    let output = asciinum(&["--keep-going", "--strict"], "123456\n61\n0\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nz\n0");
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn test_input_output_files() {
+    let dir = std::env::temp_dir();
+    let input = dir.join(format!("asciinum-input-{}", std::process::id()));
+    let output_file = dir.join(format!("asciinum-output-{}", std::process::id()));
+    let input_str = input.to_str().expect("temp path is utf-8");
+    let output_str = output_file.to_str().expect("temp path is utf-8");
+
+    std::fs::write(&input, "123456\n61\n").expect("can write temp file");
+    let output = asciinum(&["--input", input_str], "0\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nz");
+
+    let output = asciinum(&["--input", input_str, "--output", output_str], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        std::fs::read(&output_file).expect("output file exists"),
+        b"L7C\nz"
+    );
+
+    let output = asciinum(&["--output", output_str, "-d"], "L7C\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        std::fs::read(&output_file).expect("output file exists"),
+        b"123456"
+    );
+
+    std::fs::remove_file(&input).expect("can remove temp file");
+    std::fs::remove_file(&output_file).expect("can remove temp file");
+    let output = asciinum(&["--input", input_str], "0\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+
+    let output = asciinum(
+        &["--output", dir.to_str().expect("temp path is utf-8")],
+        "0\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_number_args() {
+    let output = asciinum(&["dao", "123456", "61"], "0\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nz");
+
+    let output = asciinum(&["123456", "0x3d"], "0\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nz");
+
+    let output = asciinum(&["ddi", "123", "abc", "0"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"et\na");
+    assert!(String::from_utf8(output.stderr)
+        .expect("stderr is utf-8")
+        .contains("`abc`"));
+
+    let output = asciinum(&["123", "--help"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.starts_with(b"Reads numbers"));
+}
+
+#[test]
+fn test_show_corpus() {
+    let output = asciinum(&["--show-corpus"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        output.stdout,
+        b"0123456789AaBbCcDdEeFfGgHhIiJjKkLlMmNnOoPpQqRrSsTtUuVvWwXxYyZz\n62 characters\n"
+    );
+
+    let output = asciinum(&["udi", "--show-corpus"], "123\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        output.stdout,
+        b"!\"#$%&'()*+,-.:;<=>?@[\\]^_`{|}~abcdefghijklmnopqrstuvwxyz\n57 characters\n"
+    );
+
+    let alphabet = std::env::temp_dir().join(format!("asciinum-corpus-{}", std::process::id()));
+    std::fs::write(&alphabet, "αβγ\n").expect("can write temp file");
+    let output = asciinum(
+        &[
+            "--show-corpus",
+            "--alphabet",
+            alphabet.to_str().expect("temp path is utf-8"),
+        ],
+        "",
+    );
+    std::fs::remove_file(&alphabet).expect("can remove temp file");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, "αβγ\n3 characters\n".as_bytes());
+
+    let output = asciinum(&["xyz", "--show-corpus"], "");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_bad_radixopt() {
+    let output = asciinum(&["dax"], "123\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        output.stderr,
+        b"couldn't parse program arg `dax`: third character of radix arg must be one of these: {i,s,o}\n"
+    );
+}
+
+#[test]
+fn test_checksum() {
+    let output = asciinum(&["--checksum", "123456", "0"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7CH\n00");
+
+    let output = asciinum(&["-d", "--checksum"], "L7CH\nL8CH\n00\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"123456\n0");
+    assert_eq!(
+        output.stderr,
+        b"couldn't decode `L8CH`: check symbol doesn't match\n"
+    );
+}
+
+#[test]
+fn test_stats() {
+    let output = asciinum(&["--stats"], "123456\nabc\n61\n-1\n0\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C\nz\n0");
+    let stderr = String::from_utf8(output.stderr).expect("stderr is utf-8");
+    assert_eq!(
+        stderr.lines().last(),
+        Some("converted: 3, errors: 2, first error: line 2")
+    );
+
+    let output = asciinum(&["--stats", "--strict"], "123456\nabc\n61\n");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).expect("stderr is utf-8");
+    assert_eq!(
+        stderr.lines().last(),
+        Some("converted: 1, errors: 1, first error: line 2")
+    );
+
+    let output = asciinum(&["--stats", "-d"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stderr, b"converted: 0, errors: 0\n");
+
+    let output = asciinum(&[], "123456\n");
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn test_comment_char() {
+    let input = "# some ids\n123456\n\n  # 61\n61\n";
+    let output = asciinum(&["--comment-char", "#"], input);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nz");
+    assert!(output.stderr.is_empty());
+
+    let output = asciinum(&[], input);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C\nz");
+
+    let output = asciinum(&["--comment-char", "ab"], "");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_json() {
+    let output = asciinum(&["--json", "aao"], "1\n123456\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).expect("stdout is utf-8"),
+        "{\"input\": 1, \"output\": \"\\\"\"}\n{\"input\": 123456, \"output\": \".y2\"}"
+    );
+}
+
+#[test]
+fn test_max_width() {
+    // 3843 is the largest number with 2 digits in base 62
+    let output = asciinum(&["--max-width", "2"], "3843\n3844\n61\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"zz\nz");
+    assert_eq!(
+        output.stderr,
+        b"couldn't convert `3844`: needs 3 characters, more than maximum width 2\n"
+    );
+
+    // check symbol counts too
+    let output = asciinum(&["--checksum", "--max-width", "3"], "3843\n3844\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout.len(), 3);
+}
+
+#[test]
+fn test_verify() {
+    let input = "0\n1\n123456\n340282366920938463463374607431768211455\n";
+    let output = asciinum(&["--verify", "aai"], input);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, asciinum(&["aai"], input).stdout);
+    assert!(output.stderr.is_empty());
+
+    let alphabet = std::env::temp_dir().join(format!("asciinum-verify-{}", std::process::id()));
+    let alphabet_str = alphabet.to_str().expect("temp path is utf-8");
+    std::fs::write(&alphabet, "αβγδε\n").expect("can write temp file");
+    let output = asciinum(&["--verify", "--alphabet", alphabet_str], input);
+    assert_eq!(output.status.code(), Some(0));
+
+    // duplicated characters would break decoding, such an alphabet never
+    // gets to conversion
+    std::fs::write(&alphabet, "αβγδα\n").expect("can write temp file");
+    let output = asciinum(&["--verify", "--alphabet", alphabet_str], input);
+    std::fs::remove_file(&alphabet).expect("can remove temp file");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).expect("stderr is utf-8");
+    assert!(stderr.contains("appears more than once"), "{}", stderr);
+}
+
+#[test]
+fn test_delimiter_piped() {
+    // output isn't a terminal here, so there's no newline after the last
+    // record, and explicit delimiters are written exactly
+    let output = asciinum(&["--delimiter", ","], "123456\n61\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C,z");
+
+    let output = asciinum(&["-0"], "123456\n61\n");
+    assert_eq!(output.stdout, b"L7C\0z");
+
+    let output = asciinum(&[], "123456\n61\n");
+    assert_eq!(output.stdout, b"L7C\nz");
+}
+
+#[test]
+fn test_field() {
+    let output = asciinum(&["--field", "2"], "alice,123456,x\nbob,61\ncarol\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C\nz");
+    assert_eq!(output.stderr, b"couldn't find field 2 in `carol`\n");
+
+    let output = asciinum(
+        &["--field", "3", "--field-delimiter", "\t"],
+        "a\tb\t123456\nc\td\t61\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nz");
+}
+
+#[test]
+fn test_keep_blank() {
+    let input = "123456\n\n61\n";
+    let output = asciinum(&[], input);
+    assert_eq!(output.stdout, b"L7C\nz");
+
+    let output = asciinum(&["--keep-blank"], input);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\n\nz");
+
+    // every input line has an output line
+    let input = "# ids\n123456\nabc\n\n61\n";
+    let output = asciinum(&["--keep-blank", "--comment-char", "#"], input);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"\nL7C\n\n\nz");
+}
+
+#[test]
+fn test_exit_status() {
+    // every record is converted
+    let output = asciinum(&[], "123456\n61\n");
+    assert_eq!(output.status.code(), Some(0));
+
+    // a record that can't be converted
+    let output = asciinum(&[], "123456\nabc\n61\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C\nz");
+    let output = asciinum(&["--strict"], "123456\nabc\n61\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C");
+
+    // invalid program args
+    let output = asciinum(&["-d", "dao", "L7C"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        output.stderr,
+        b"too many arguments. use `--help` for more info.\n"
+    );
+    let output = asciinum(&["xyz"], "");
+    assert_eq!(output.status.code(), Some(1));
+
+    // input that can't be opened, or can't be read after opening it
+    let output = asciinum(&["--input", "/nonexistent/asciinum-input"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let dir = std::env::temp_dir();
+    let output = asciinum(&["--input", dir.to_str().expect("utf-8 path")], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn test_base() {
+    let output = asciinum(&["--base", "16"], "255\n48879\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"ff\nbeef");
+    let output = asciinum(&["-d", "--base", "16"], "ff\nbeef\n");
+    assert_eq!(output.stdout, b"255\n48879");
+
+    let output = asciinum(&["--base", "62", "61", "62", "123456"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"Z\n10\nw7e");
+
+    let output = asciinum(&["--base", "63"], "1\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stderr, b"`--base` needs a number from 2 to 62\n");
+}
+
+#[test]
+fn test_trim() {
+    let input = "  123  \n\t61\t\n";
+    let output = asciinum(&["--trim", "none"], input);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"");
+    let output = asciinum(&["--trim", "control"], input);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"z");
+    let output = asciinum(&["--trim", "whitespace"], input);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"1z\nz");
+}
+
+#[test]
+fn test_no_final_newline() {
+    let output = asciinum(&[], "123456\n61");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nz");
+
+    let output = asciinum(&["-d"], "z");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"61");
+}
+
+#[test]
+fn test_max_line_bytes() {
+    let input = format!("123456\n{}\n61\n", "1".repeat(100));
+    let output = asciinum(&["--max-line-bytes", "8"], &input);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C\nz");
+    assert_eq!(
+        output.stderr,
+        b"couldn't parse a line: it's longer than 8 bytes\n"
+    );
+
+    // default limit is large enough for any number
+    let output = asciinum(&[], &input);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(output.stdout, b"L7C\nz");
+    assert!(output
+        .stderr
+        .ends_with(b"number too large to fit in target type\n"));
+}
+
+#[test]
+fn test_case() {
+    let output = asciinum(&["--uppercase"], "123456\n61\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"L7C\nZ");
+    assert_eq!(
+        output.stderr,
+        b"warning: `--uppercase` changes characters of the corpus, outputs may not decode back\n"
+    );
+
+    // insensitive letters are lowercase already, and decode either case
+    let plain = asciinum(&["dai"], "123456\n61\n");
+    let output = asciinum(&["dai", "--lowercase"], "123456\n61\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stderr.is_empty());
+    assert_eq!(output.stdout, plain.stdout);
+    let output = asciinum(&["--uppercase", "dai"], "123456\n61\n");
+    assert!(output.stderr.is_empty());
+    assert_eq!(output.stdout, plain.stdout.to_ascii_uppercase());
+}
@@ -0,0 +1,21 @@
+use std::process::Command;
+
+/// Library needs to build without its `std` feature, see the `Features`
+/// section of the crate docs.
+#[test]
+fn test_build_without_std() {
+    let target_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_std");
+    let output = Command::new(env!("CARGO"))
+        .args(["check", "--lib", "--no-default-features", "--offline"])
+        .arg("--manifest-path")
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir)
+        .output()
+        .expect("can run cargo");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
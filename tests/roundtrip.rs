@@ -0,0 +1,131 @@
+//! Randomized roundtrip tests over the whole `u128` range and every kind of
+//! corpus. Inputs come from a fixed seed, so failures are reproducible.
+
+use asciinum::*;
+
+const CASES: usize = 2000;
+
+/// xorshift64*, good enough for picking test inputs.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+    /// Random number with a random bit length, so that small numbers are as
+    /// common as large ones.
+    fn number(&mut self) -> u128 {
+        let number = (self.next_u64() as u128) << 64 | self.next_u64() as u128;
+        number >> self.below(128)
+    }
+    /// Random corpus of distinct characters, ASCII and multibyte ones mixed.
+    fn corpus(&mut self) -> String {
+        let len = 2 + self.below(200);
+        let mut corpus = String::new();
+        while corpus.chars().count() < len {
+            let ch = match self.below(3) {
+                0 => char::from(b'!' + self.below(94) as u8),
+                1 => char::from_u32(0xa1 + self.below(0x2000) as u32).expect("not a surrogate"),
+                _ => char::from_u32(0x1f300 + self.below(0x300) as u32).expect("valid char"),
+            };
+            if !corpus.contains(ch) {
+                corpus.push(ch);
+            }
+        }
+        corpus
+    }
+}
+
+fn settings() -> Vec<RadixSettings> {
+    let mut settings = Vec::new();
+    for symbols in [
+        RadixSymbols::All,
+        RadixSymbols::UnixSafe,
+        RadixSymbols::Disabled,
+    ] {
+        for numbers in [RadixNumbers::All, RadixNumbers::Disabled] {
+            for letters in [
+                RadixLetters::Insensitive,
+                RadixLetters::Sensitive,
+                RadixLetters::SensitiveOrdered,
+            ] {
+                settings.push(RadixSettings::new(
+                    symbols.clone(),
+                    numbers.clone(),
+                    letters,
+                ));
+            }
+        }
+    }
+    settings
+}
+
+fn check(converter: &AsciiConverter, number: u128) {
+    let ascii = converter.convert(number);
+    assert_eq!(
+        converter.decode(&ascii),
+        Ok(number),
+        "{:?} {}",
+        converter.corpus(),
+        number
+    );
+    assert_eq!(
+        converter.encoded_len(number),
+        ascii.chars().count(),
+        "{:?} {}",
+        converter.corpus(),
+        number
+    );
+}
+
+#[test]
+fn test_roundtrip_settings() {
+    let mut rng = Rng(0x5eed_a5c1_1a0d_0001);
+    let converters: Vec<AsciiConverter> = settings().iter().map(AsciiConverter::new).collect();
+    for _ in 0..CASES {
+        let converter = &converters[rng.below(converters.len())];
+        check(converter, rng.number());
+    }
+    for converter in &converters {
+        check(converter, 0);
+        check(converter, u128::MAX);
+    }
+}
+
+#[test]
+fn test_roundtrip_presets() {
+    let mut rng = Rng(0x5eed_a5c1_1a0d_0002);
+    for converter in [
+        AsciiConverter::unambiguous(),
+        AsciiConverter::base58(),
+        AsciiConverter::crockford_base32(),
+        AsciiConverter::crockford_base32_with_check(),
+        AsciiConverter::new(&RadixSettings::default()).with_checksum(),
+        AsciiConverter::from_corpus("αβγδε")
+            .expect("valid corpus")
+            .with_checksum(),
+    ] {
+        for _ in 0..CASES / 4 {
+            check(&converter, rng.number());
+        }
+        check(&converter, u128::MAX);
+    }
+}
+
+#[test]
+fn test_roundtrip_custom_corpus() {
+    let mut rng = Rng(0x5eed_a5c1_1a0d_0003);
+    for _ in 0..CASES / 20 {
+        let converter = AsciiConverter::from_corpus(&rng.corpus()).expect("valid corpus");
+        for _ in 0..20 {
+            check(&converter, rng.number());
+        }
+        check(&converter, u128::MAX);
+    }
+}